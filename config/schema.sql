@@ -1,18 +1,44 @@
-CREATE DATABASE stock WITH ENCODING 'UTF8';
-CREATE TABLE public.stocks (id Integer Primary Key Generated Always as Identity);
-ALTER TABLE public.stocks ADD name character varying(50);
-ALTER TABLE public.stocks ADD current_price double precision;
-ALTER TABLE public.stocks ADD eps_ttm double precision;
-ALTER TABLE public.stocks ADD pe_ratio double precision;
-ALTER TABLE public.stocks ADD total_debt_equity double precision;
-ALTER TABLE public.stocks ADD change_since character varying;
-ALTER TABLE public.stocks ADD market_cap character varying;
-ALTER TABLE public.stocks ADD peg_ratio double precision;
-ALTER TABLE public.stocks ADD price_to_book double precision;
-ALTER TABLE public.stocks ADD revenue character varying;
-ALTER TABLE public.stocks ADD gross_profit character varying;
-ALTER TABLE public.stocks ADD total_cash character varying;
-ALTER TABLE public.stocks ADD total_debt character varying;
-ALTER TABLE public.stocks ADD return_on_equity character varying;
-ALTER TABLE public.stocks ADD return_on_assets character varying;
-ALTER TABLE public.stocks ADD bvps double precision;
\ No newline at end of file
+CREATE DATABASE stock WITH ENCODING 'UTF8';
+CREATE TABLE IF NOT EXISTS public.stocks (id Integer Primary Key Generated Always as Identity);
+ALTER TABLE public.stocks ADD IF NOT EXISTS name character varying(50);
+ALTER TABLE public.stocks ADD IF NOT EXISTS current_price double precision;
+ALTER TABLE public.stocks ADD IF NOT EXISTS eps_ttm double precision;
+ALTER TABLE public.stocks ADD IF NOT EXISTS pe_ratio double precision;
+ALTER TABLE public.stocks ADD IF NOT EXISTS total_debt_equity double precision;
+ALTER TABLE public.stocks ADD IF NOT EXISTS change_since character varying;
+ALTER TABLE public.stocks ADD IF NOT EXISTS market_cap character varying;
+ALTER TABLE public.stocks ADD IF NOT EXISTS peg_ratio double precision;
+ALTER TABLE public.stocks ADD IF NOT EXISTS price_to_book double precision;
+ALTER TABLE public.stocks ADD IF NOT EXISTS revenue character varying;
+ALTER TABLE public.stocks ADD IF NOT EXISTS gross_profit character varying;
+ALTER TABLE public.stocks ADD IF NOT EXISTS total_cash character varying;
+ALTER TABLE public.stocks ADD IF NOT EXISTS total_debt character varying;
+ALTER TABLE public.stocks ADD IF NOT EXISTS return_on_equity character varying;
+ALTER TABLE public.stocks ADD IF NOT EXISTS return_on_assets character varying;
+ALTER TABLE public.stocks ADD IF NOT EXISTS bvps double precision;
+ALTER TABLE public.stocks ADD IF NOT EXISTS sector character varying;
+ALTER TABLE public.stocks ADD IF NOT EXISTS industry character varying;
+ALTER TABLE public.stocks ADD IF NOT EXISTS country character varying;
+ALTER TABLE public.stocks ADD IF NOT EXISTS currency character varying;
+CREATE TABLE IF NOT EXISTS public.transactions (id Integer Primary Key Generated Always as Identity);
+ALTER TABLE public.transactions ADD IF NOT EXISTS symbol character varying(50);
+ALTER TABLE public.transactions ADD IF NOT EXISTS kind character varying(4);
+ALTER TABLE public.transactions ADD IF NOT EXISTS quantity double precision;
+ALTER TABLE public.transactions ADD IF NOT EXISTS price double precision;
+ALTER TABLE public.transactions ADD IF NOT EXISTS fee double precision;
+ALTER TABLE public.transactions ADD IF NOT EXISTS date character varying(10);
+CREATE TABLE IF NOT EXISTS public.watchlists (id Integer Primary Key Generated Always as Identity);
+ALTER TABLE public.watchlists ADD IF NOT EXISTS name character varying(50) UNIQUE;
+CREATE TABLE IF NOT EXISTS public.watchlist_stocks (id Integer Primary Key Generated Always as Identity);
+ALTER TABLE public.watchlist_stocks ADD IF NOT EXISTS watchlist character varying(50);
+ALTER TABLE public.watchlist_stocks ADD IF NOT EXISTS name character varying(50);
+CREATE TABLE IF NOT EXISTS public.statements (id Integer Primary Key Generated Always as Identity);
+ALTER TABLE public.statements ADD IF NOT EXISTS symbol character varying(50);
+ALTER TABLE public.statements ADD IF NOT EXISTS statement character varying(10);
+ALTER TABLE public.statements ADD IF NOT EXISTS frequency character varying(10);
+ALTER TABLE public.statements ADD IF NOT EXISTS period character varying(10);
+ALTER TABLE public.statements ADD IF NOT EXISTS item character varying(100);
+ALTER TABLE public.statements ADD IF NOT EXISTS value double precision;
+CREATE TABLE IF NOT EXISTS public.alerts (id Integer Primary Key Generated Always as Identity);
+ALTER TABLE public.alerts ADD IF NOT EXISTS symbol character varying(50);
+ALTER TABLE public.alerts ADD IF NOT EXISTS condition character varying;
@@ -0,0 +1,7 @@
+pub mod statistics;
+pub mod compare;
+pub mod benchmark;
+pub mod screener;
+pub mod peers;
+pub mod alerts;
+pub mod watch;
\ No newline at end of file
@@ -0,0 +1,126 @@
+pub mod alerts {
+    use std::collections::HashSet;
+
+    use crate::analytics::screener::screener::Expression;
+    use crate::database::queries::queries::Stock;
+    use crate::output::table::table::print_table;
+
+    /// Exit code of `alert check` when an alert triggered, so scripts can act on it.
+    pub const TRIGGERED_EXIT_CODE: i32 = 2;
+
+    /// An alert on a stock. The condition is saved as it was entered, e.g. "price < 150",
+    /// and uses the same syntax as a screen.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Alert {
+        pub id: i32,
+        pub symbol: String,
+        pub condition: String,
+    }
+
+    /// Symbols of the stocks the alerts are set on, each once.
+    pub fn symbols(alerts: &[Alert]) -> Vec<String> {
+        let mut symbols: Vec<String> = Vec::new();
+
+        for alert in alerts {
+            if !symbols.contains(&alert.symbol) {
+                symbols.push(alert.symbol.clone());
+            }
+        }
+
+        symbols
+    }
+
+    /// Drops the alerts on a stock which couldn't be updated, so they aren't checked
+    /// against outdated values.
+    pub fn skip_alerts(alerts: &mut Vec<Alert>, symbol: &str) {
+        if alerts.iter().any(|alert| alert.symbol.eq_ignore_ascii_case(symbol)) {
+            println!("Alerts on {} are not checked, since the stock could not be updated.", symbol.to_uppercase());
+        }
+
+        alerts.retain(|alert| !alert.symbol.eq_ignore_ascii_case(symbol));
+    }
+
+    /// Alerts whose condition holds for their stock. Alerts on stocks which aren't
+    /// saved or whose condition can't be parsed anymore don't trigger. Neither do alerts
+    /// on a field without a value, since values which couldn't be scraped are saved as 0.0.
+    pub fn triggered<'a>(alerts: &'a [Alert], stocks: &'a [Stock]) -> Vec<(&'a Alert, &'a Stock, Expression)> {
+        alerts.iter()
+            .filter_map(|alert| {
+                let stock = stocks.iter().find(|stock| stock.name.eq_ignore_ascii_case(&alert.symbol))?;
+                let expression = Expression::parse(&alert.condition).ok()?;
+
+                if expression.fields().iter().any(|field| matches!(stock.field(field), None | Some(0.0))) {
+                    return None
+                }
+
+                match expression.evaluate(stock) {
+                    true => Some((alert, stock, expression)),
+                    false => None
+                }
+            })
+            .collect()
+    }
+
+    pub fn print_alerts(alerts: &[Alert]) {
+        if alerts.is_empty() {
+            return println!("No alerts are set. Add one with e.g. 'alert add aapl price < 150'.")
+        }
+
+        let rows: Vec<Vec<String>> = alerts.iter()
+            .map(|alert| vec![alert.id.to_string(), alert.symbol.to_uppercase(), alert.condition.clone()])
+            .collect();
+
+        print_table(&["id", "symbol", "condition"], &rows);
+    }
+
+    /// Prints the triggered alerts with the current values of the fields of their
+    /// condition and returns whether any alert triggered.
+    pub fn print_triggered(alerts: &[Alert], stocks: &[Stock]) -> bool {
+        let triggered = triggered(alerts, stocks);
+
+        if triggered.is_empty() {
+            println!("No alert was triggered.");
+            return false
+        }
+
+        print_triggered_table(&triggered.iter().collect::<Vec<_>>());
+        println!("Triggered alerts: {} of {}", triggered.len(), alerts.len());
+
+        true
+    }
+
+    /// Prints only the alerts which weren't triggered at the previous check. The ids of the
+    /// triggered alerts are kept in `triggered_before`, so an alert is reported again once
+    /// its condition stopped holding in between. Alerts which weren't checked keep their state.
+    pub fn print_newly_triggered(alerts: &[Alert], stocks: &[Stock], triggered_before: &mut HashSet<i32>) {
+        let triggered = triggered(alerts, stocks);
+        let new: Vec<&(&Alert, &Stock, Expression)> = triggered.iter()
+            .filter(|(alert, _, _)| !triggered_before.contains(&alert.id))
+            .collect();
+
+        if new.is_empty() {
+            println!("No new alert was triggered.");
+        } else {
+            print_triggered_table(&new);
+        }
+
+        triggered_before.retain(|id| !alerts.iter().any(|alert| alert.id == *id));
+        triggered_before.extend(triggered.iter().map(|(alert, _, _)| alert.id));
+    }
+
+    /// Triggered alerts with the current values of the fields of their condition.
+    fn print_triggered_table(triggered: &[&(&Alert, &Stock, Expression)]) {
+        let rows: Vec<Vec<String>> = triggered.iter()
+            .map(|(alert, stock, expression)| {
+                let values = expression.fields().iter()
+                    .map(|field| format!("{}: {}", field, stock.display(field).unwrap_or_default()))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                vec![alert.id.to_string(), alert.symbol.to_uppercase(), alert.condition.clone(), values]
+            })
+            .collect();
+
+        print_table(&["id", "symbol", "condition", "current"], &rows);
+    }
+}
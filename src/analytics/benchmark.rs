@@ -0,0 +1,155 @@
+pub mod benchmark {
+    use std::{
+        collections::HashMap,
+        fs::OpenOptions, 
+        io::{BufWriter, Write}
+    };
+
+    use chrono::NaiveDate;
+
+    use crate::analytics::statistics::statistics::{daily_returns, mean};
+    use crate::errors::error_handler::error_handler::BenchmarkError;
+    use crate::output::table::table::print_table;
+    use crate::portfolio::returns::returns::calculate as portfolio_returns;
+    use crate::portfolio::transactions::transactions::Transaction;
+    use crate::scraper::financial_data::get_financial_data::historical_prices;
+
+    pub fn read_benchmark() -> Result<String, BenchmarkError> {
+        let file = std::fs::File::open("config/benchmark.yml")?;
+        let benchmark: serde_yaml::Mapping = serde_yaml::from_reader(file)?;
+
+        Ok(benchmark["benchmark"].as_str().unwrap_or("").to_string())
+    }
+
+    pub fn set_benchmark(symbol: String) -> Result<(), BenchmarkError> {
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open("config/benchmark.yml")?;
+
+        let mut writer = BufWriter::new(file);
+        write!(writer, "benchmark: \"{}\"", symbol.to_uppercase())?;
+
+        Ok(())
+    }
+
+    pub struct Comparison {
+        pub name: String,
+        pub total_return: f64,
+        pub excess_return: f64,
+        pub beta: f64,
+        pub correlation: f64,
+    }
+
+    /// Beta and correlation of daily returns on the dates both series have in common.
+    pub fn beta_and_correlation(returns: &[(NaiveDate, f64)], benchmark: &HashMap<NaiveDate, f64>) -> (f64, f64) {
+        let pairs: Vec<(f64, f64)> = returns.iter()
+            .filter_map(|(date, value)| benchmark.get(date).map(|benchmark_value| (*value, *benchmark_value)))
+            .collect();
+
+        if pairs.len() < 2 {
+            return (0.0, 0.0)
+        }
+
+        let values: Vec<f64> = pairs.iter().map(|(value, _)| *value).collect();
+        let benchmark_values: Vec<f64> = pairs.iter().map(|(_, value)| *value).collect();
+        let (mean_value, mean_benchmark) = (mean(&values), mean(&benchmark_values));
+
+        let covariance = pairs.iter()
+            .map(|(value, benchmark_value)| (value - mean_value) * (benchmark_value - mean_benchmark))
+            .sum::<f64>() / (pairs.len() - 1) as f64;
+        let variance = |values: &[f64], avg: f64| {
+            values.iter().map(|value| (value - avg).powi(2)).sum::<f64>() / (values.len() - 1) as f64
+        };
+        let (variance_value, variance_benchmark) = (variance(&values, mean_value), variance(&benchmark_values, mean_benchmark));
+
+        let beta = if variance_benchmark > 0.0 { covariance / variance_benchmark } else { 0.0 };
+        let correlation = if variance_value > 0.0 && variance_benchmark > 0.0 {
+            covariance / (variance_value.sqrt() * variance_benchmark.sqrt())
+        } else {
+            0.0
+        };
+
+        (beta, correlation)
+    }
+
+    fn dated_returns(prices: &[(NaiveDate, f64)]) -> Vec<(NaiveDate, f64)> {
+        prices.iter()
+            .skip(1)
+            .map(|(date, _)| *date)
+            .zip(daily_returns(prices))
+            .collect()
+    }
+
+    pub async fn print_benchmark(
+        benchmark: String, symbols: Vec<String>, transactions: &[Transaction], from: NaiveDate, to: NaiveDate
+    ) {
+        if from >= to {
+            return println!("The start date needs to lie before the end date.")
+        }
+
+        let benchmark_prices = match historical_prices(&benchmark, from, to).await {
+            Ok(prices) if prices.len() > 1 => prices,
+            Ok(_) => return println!("Not enough prices for benchmark {}.", benchmark),
+            Err(e) => return println!("{}", e)
+        };
+
+        let benchmark_return = benchmark_prices[benchmark_prices.len() - 1].1 / benchmark_prices[0].1 - 1.0;
+        let benchmark_returns: HashMap<NaiveDate, f64> = dated_returns(&benchmark_prices).into_iter().collect();
+
+        let mut comparisons = Vec::new();
+
+        for symbol in symbols {
+            match historical_prices(&symbol, from, to).await {
+                Ok(prices) if prices.len() > 1 => {
+                    let total_return = prices[prices.len() - 1].1 / prices[0].1 - 1.0;
+                    let (beta, correlation) = beta_and_correlation(&dated_returns(&prices), &benchmark_returns);
+
+                    comparisons.push(Comparison {
+                        name: symbol.to_uppercase(),
+                        total_return,
+                        excess_return: total_return - benchmark_return,
+                        beta,
+                        correlation,
+                    });
+                },
+                Ok(_) => println!("Not enough prices for {}.", symbol.to_uppercase()),
+                Err(e) => println!("{}", e)
+            }
+        }
+
+        comparisons.sort_by(|a, b| b.excess_return.total_cmp(&a.excess_return));
+
+        if !transactions.is_empty() {
+            if let Some(returns) = portfolio_returns(transactions, from, to).await {
+                let (beta, correlation) = beta_and_correlation(&returns.daily_returns, &benchmark_returns);
+
+                comparisons.push(Comparison {
+                    name: "Portfolio".to_string(),
+                    total_return: returns.time_weighted,
+                    excess_return: returns.time_weighted - benchmark_return,
+                    beta,
+                    correlation,
+                });
+            }
+        }
+
+        println!(
+            "Benchmark {} from {} to {}: {:.2}%", 
+            benchmark.to_uppercase(), from.format("%d.%m.%Y"), to.format("%d.%m.%Y"), benchmark_return * 100.0
+        );
+
+        let rows: Vec<Vec<String>> = comparisons.iter()
+            .map(|comparison| vec![
+                comparison.name.clone(),
+                format!("{:.2}%", comparison.total_return * 100.0),
+                format!("{:.2}%", comparison.excess_return * 100.0),
+                format!("{:.2}", comparison.beta),
+                format!("{:.2}", comparison.correlation),
+            ])
+            .collect();
+
+        print_table(&["Symbol", "Return", "Excess Return", "Beta", "Correlation"], &rows);
+    }
+}
@@ -0,0 +1,121 @@
+pub mod compare {
+    use chrono::{Local, NaiveDate};
+
+    use crate::output::table::table::print_table;
+    use crate::scraper::financial_data::get_financial_data::{historical_prices, parse_naive_date};
+
+    const NORMALISED_ROWS: usize = 12;
+
+    pub struct Performance {
+        pub symbol: String,
+        pub prices: Vec<(NaiveDate, f64)>,
+    }
+
+    impl Performance {
+        pub fn start_price(&self) -> f64 {
+            self.prices[0].1
+        }
+
+        pub fn end_price(&self) -> f64 {
+            self.prices[self.prices.len() - 1].1
+        }
+
+        pub fn total_return(&self) -> f64 {
+            (self.end_price() / self.start_price() - 1.0) * 100.0
+        }
+
+        /// Price on the given date or the last one before it, indexed to 100 at the start.
+        pub fn normalised_at(&self, date: NaiveDate) -> Option<f64> {
+            self.prices.iter()
+                .take_while(|(price_date, _)| *price_date <= date)
+                .last()
+                .map(|(_, price)| price / self.start_price() * 100.0)
+        }
+    }
+
+    pub async fn print_comparison(symbols: Vec<String>, since: String, normalise: bool) {
+        let from = match parse_naive_date(since) {
+            Ok(date) => date,
+            Err(e) => return println!("{}", e)
+        };
+
+        let to = Local::now().date_naive();
+
+        if from >= to {
+            return println!("The start date needs to lie in the past.")
+        }
+
+        let mut performances = Vec::new();
+
+        for symbol in symbols {
+            match historical_prices(symbol.as_str(), from, to).await {
+                Ok(prices) if prices.len() > 1 => performances.push(Performance { 
+                    symbol: symbol.to_uppercase(), prices 
+                }),
+                Ok(_) => println!("Not enough prices for {} since {}.", symbol.to_uppercase(), from.format("%d.%m.%Y")),
+                Err(e) => println!("{}", e)
+            }
+        }
+
+        if performances.is_empty() {
+            return
+        }
+
+        performances.sort_by(|a, b| b.total_return().total_cmp(&a.total_return()));
+
+        println!("Performance since {}", from.format("%d.%m.%Y"));
+
+        let rows: Vec<Vec<String>> = performances.iter()
+            .enumerate()
+            .map(|(i, performance)| vec![
+                (i + 1).to_string(),
+                performance.symbol.clone(),
+                performance.prices[0].0.format("%d.%m.%Y").to_string(),
+                format!("{:.2}", performance.start_price()),
+                format!("{:.2}", performance.end_price()),
+                format!("{:.2}%", performance.total_return()),
+            ])
+            .collect();
+
+        print_table(&["Rank", "Symbol", "Start", "Start Price", "End Price", "Return"], &rows);
+
+        if normalise {
+            println!();
+            print_normalised(&performances);
+        }
+    }
+
+    fn print_normalised(performances: &[Performance]) {
+        let mut dates: Vec<NaiveDate> = performances.iter()
+            .flat_map(|performance| performance.prices.iter().map(|(date, _)| *date))
+            .collect();
+        dates.sort();
+        dates.dedup();
+
+        let step = (dates.len() / NORMALISED_ROWS).max(1);
+        let mut sampled: Vec<NaiveDate> = dates.iter().step_by(step).copied().collect();
+
+        if let Some(last) = dates.last() {
+            if sampled.last() != Some(last) {
+                sampled.push(*last);
+            }
+        }
+
+        let mut headers = vec!["Date"];
+        headers.extend(performances.iter().map(|performance| performance.symbol.as_str()));
+
+        let rows: Vec<Vec<String>> = sampled.iter()
+            .map(|date| {
+                let mut row = vec![date.format("%d.%m.%Y").to_string()];
+                row.extend(performances.iter().map(|performance| match performance.normalised_at(*date) {
+                    Some(value) => format!("{:.2}", value),
+                    None => "-".to_string()
+                }));
+                row
+            })
+            .collect();
+
+        println!("Normalised to 100 at the start date");
+        print_table(&headers, &rows);
+    }
+}
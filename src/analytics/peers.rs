@@ -0,0 +1,105 @@
+pub mod peers {
+    use crate::database::queries::queries::{Stock, parse_amount};
+    use crate::output::table::table::print_table;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Better {
+        Higher,
+        Lower,
+        Neither,
+    }
+
+    /// Metrics of the comparison in the order they're printed and which value is better.
+    const METRICS: &[(&str, Better)] = &[
+        ("current_price", Better::Neither),
+        ("change_since", Better::Higher),
+        ("market_cap", Better::Neither),
+        ("eps_ttm", Better::Higher),
+        ("pe_ratio", Better::Lower),
+        ("peg_ratio", Better::Lower),
+        ("price_to_book", Better::Lower),
+        ("bvps", Better::Neither),
+        ("revenue", Better::Higher),
+        ("gross_profit", Better::Higher),
+        ("total_cash", Better::Higher),
+        ("total_debt", Better::Lower),
+        ("total_debt_equity", Better::Lower),
+        ("return_on_equity", Better::Higher),
+        ("return_on_assets", Better::Higher),
+        ("sector", Better::Neither),
+        ("industry", Better::Neither),
+        ("country", Better::Neither),
+        ("currency", Better::Neither),
+    ];
+
+    /// Ratios which are missing at zero and meaningless below, e.g. a negative P/E means losses.
+    const VALUATION_RATIOS: &[&str] = &["pe_ratio", "peg_ratio", "price_to_book"];
+
+    /// Value of a metric which can be ranked, or None if it's missing.
+    fn value(stock: &Stock, metric: &str) -> Option<f64> {
+        match metric {
+            "change_since" => stock.change_since.split(' ').next().and_then(parse_amount),
+            metric if VALUATION_RATIOS.contains(&metric) => stock.field(metric).filter(|value| *value > 0.0),
+            _ => stock.field(metric)
+        }
+    }
+
+    /// Indices of the best and the worst value. Missing values aren't ranked.
+    pub fn best_and_worst(values: &[Option<f64>], better: Better) -> Option<(usize, usize)> {
+        let ranked: Vec<(usize, f64)> = values.iter()
+            .enumerate()
+            .filter_map(|(i, value)| value.map(|value| (i, value)))
+            .collect();
+
+        let (lowest, highest) = (
+            ranked.iter().min_by(|a, b| a.1.total_cmp(&b.1))?,
+            ranked.iter().max_by(|a, b| a.1.total_cmp(&b.1))?,
+        );
+
+        if ranked.len() < 2 || lowest.1 == highest.1 {
+            return None
+        }
+
+        match better {
+            Better::Higher => Some((highest.0, lowest.0)),
+            Better::Lower => Some((lowest.0, highest.0)),
+            Better::Neither => None
+        }
+    }
+
+    pub fn print_peers(stocks: &[Stock]) {
+        if stocks.is_empty() {
+            return println!("No stocks to compare.")
+        }
+
+        let mut headers = vec!["metric".to_string()];
+        // Padded like the values, which end in a marker.
+        headers.extend(stocks.iter().map(|stock| format!("{}  ", stock.name.to_uppercase())));
+
+        let rows: Vec<Vec<String>> = METRICS.iter()
+            .map(|(metric, better)| {
+                let values: Vec<Option<f64>> = stocks.iter().map(|stock| value(stock, metric)).collect();
+                let marked = best_and_worst(&values, *better);
+
+                let mut row = vec![metric.to_string()];
+                row.extend(stocks.iter().enumerate().map(|(i, stock)| {
+                    let display = stock.display(metric).unwrap_or_default();
+
+                    match marked {
+                        Some((best, _)) if best == i => format!("{} *", display),
+                        Some((_, worst)) if worst == i => format!("{} !", display),
+                        _ => format!("{}  ", display)
+                    }
+                }));
+
+                row
+            })
+            .collect();
+
+        let headers: Vec<&str> = headers.iter().map(|header| header.as_str()).collect();
+        print_table(&headers, &rows);
+
+        println!();
+        println!("* best, ! worst value of a metric.");
+    }
+}
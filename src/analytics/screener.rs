@@ -0,0 +1,238 @@
+pub mod screener {
+    use std::{iter::Peekable, vec::IntoIter};
+
+    use crate::database::queries::queries::{Stock, NUMERIC_FIELDS, field_kind, parse_amount, sql_column};
+    use crate::errors::error_handler::error_handler::ScreenError;
+    use crate::output::listing::listing::resolve_column;
+    use crate::output::table::table::print_table;
+
+    /// Difference below which two values are equal, in Rust as well as in SQL.
+    const TOLERANCE: f64 = 1e-9;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Operator {
+        Less,
+        LessOrEqual,
+        Greater,
+        GreaterOrEqual,
+        Equal,
+        NotEqual,
+    }
+
+    impl Operator {
+        fn parse(value: &str) -> Option<Operator> {
+            match value {
+                "<" => Some(Operator::Less),
+                "<=" => Some(Operator::LessOrEqual),
+                ">" => Some(Operator::Greater),
+                ">=" => Some(Operator::GreaterOrEqual),
+                "=" | "==" => Some(Operator::Equal),
+                "!=" | "<>" => Some(Operator::NotEqual),
+                _ => None
+            }
+        }
+
+        fn as_sql(&self) -> &'static str {
+            match self {
+                Operator::Less => "<",
+                Operator::LessOrEqual => "<=",
+                Operator::Greater => ">",
+                Operator::GreaterOrEqual => ">=",
+                Operator::Equal => "=",
+                Operator::NotEqual => "<>",
+            }
+        }
+
+        fn compare(&self, left: f64, right: f64) -> bool {
+            match self {
+                Operator::Less => left < right,
+                Operator::LessOrEqual => left <= right,
+                Operator::Greater => left > right,
+                Operator::GreaterOrEqual => left >= right,
+                Operator::Equal => (left - right).abs() < TOLERANCE,
+                Operator::NotEqual => (left - right).abs() >= TOLERANCE,
+            }
+        }
+    }
+
+    /// A screen like "pe_ratio < 15 and (peg_ratio < 1 or return_on_equity > 20)".
+    /// `and` binds stronger than `or`.
+    #[derive(Debug, Clone)]
+    pub enum Expression {
+        Comparison { field: String, operator: Operator, value: f64 },
+        And(Box<Expression>, Box<Expression>),
+        Or(Box<Expression>, Box<Expression>),
+    }
+
+    impl Expression {
+        pub fn parse(input: &str) -> Result<Expression, ScreenError> {
+            let mut tokens = tokenize(input).into_iter().peekable();
+            let expression = parse_or(&mut tokens)?;
+
+            match tokens.next() {
+                Some(token) => Err(ScreenError::UnexpectedToken { token }),
+                None => Ok(expression)
+            }
+        }
+
+        /// A stock without a value for a field, e.g. because it couldn't be scraped, doesn't match.
+        pub fn evaluate(&self, stock: &Stock) -> bool {
+            match self {
+                Expression::Comparison { field, operator, value } => stock.field(field)
+                    .map(|field| operator.compare(field, *value))
+                    .unwrap_or(false),
+                Expression::And(left, right) => left.evaluate(stock) && right.evaluate(stock),
+                Expression::Or(left, right) => left.evaluate(stock) || right.evaluate(stock),
+            }
+        }
+
+        /// WHERE clause of the screen. Field names are checked against the known fields 
+        /// when parsing and every value is added to `params` as a bound parameter.
+        pub fn to_sql(&self, params: &mut Vec<f64>) -> String {
+            match self {
+                Expression::Comparison { field, operator, value } => {
+                    params.push(*value);
+                    let column = sql_column(field).unwrap();
+
+                    match operator {
+                        Operator::Equal => format!("ABS({} - ${}) < {:e}", column, params.len(), TOLERANCE),
+                        Operator::NotEqual => format!("ABS({} - ${}) >= {:e}", column, params.len(), TOLERANCE),
+                        _ => format!("{} {} ${}", column, operator.as_sql(), params.len())
+                    }
+                },
+                Expression::And(left, right) => format!("({} AND {})", left.to_sql(params), right.to_sql(params)),
+                Expression::Or(left, right) => format!("({} OR {})", left.to_sql(params), right.to_sql(params)),
+            }
+        }
+
+        pub fn fields(&self) -> Vec<String> {
+            match self {
+                Expression::Comparison { field, .. } => vec![field.clone()],
+                Expression::And(left, right) | Expression::Or(left, right) => {
+                    let mut fields = left.fields();
+                    for field in right.fields() {
+                        if !fields.contains(&field) {
+                            fields.push(field);
+                        }
+                    }
+                    fields
+                }
+            }
+        }
+    }
+
+    fn tokenize(input: &str) -> Vec<String> {
+        let mut tokens = Vec::new();
+        let mut chars = input.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                ' ' | '\t' | '\n' => continue,
+                '(' | ')' => tokens.push(c.to_string()),
+                '<' | '>' | '=' | '!' => {
+                    let mut operator = c.to_string();
+                    if let Some(next) = chars.peek() {
+                        if matches!(next, '=' | '>') {
+                            operator.push(*next);
+                            chars.next();
+                        }
+                    }
+                    tokens.push(operator)
+                },
+                _ => {
+                    let mut word = c.to_string();
+                    while let Some(next) = chars.peek() {
+                        if next.is_whitespace() || matches!(next, '(' | ')' | '<' | '>' | '=' | '!') {
+                            break
+                        }
+                        word.push(*next);
+                        chars.next();
+                    }
+                    tokens.push(word)
+                }
+            }
+        }
+
+        tokens
+    }
+
+    type Tokens = Peekable<IntoIter<String>>;
+
+    fn parse_or(tokens: &mut Tokens) -> Result<Expression, ScreenError> {
+        let mut expression = parse_and(tokens)?;
+
+        while tokens.peek().map(|token| token.eq_ignore_ascii_case("or")).unwrap_or(false) {
+            tokens.next();
+            expression = Expression::Or(Box::new(expression), Box::new(parse_and(tokens)?));
+        }
+
+        Ok(expression)
+    }
+
+    fn parse_and(tokens: &mut Tokens) -> Result<Expression, ScreenError> {
+        let mut expression = parse_comparison(tokens)?;
+
+        while tokens.peek().map(|token| token.eq_ignore_ascii_case("and")).unwrap_or(false) {
+            tokens.next();
+            expression = Expression::And(Box::new(expression), Box::new(parse_comparison(tokens)?));
+        }
+
+        Ok(expression)
+    }
+
+    fn parse_comparison(tokens: &mut Tokens) -> Result<Expression, ScreenError> {
+        let token = tokens.next().ok_or(ScreenError::UnexpectedEnd)?;
+
+        if token == "(" {
+            let expression = parse_or(tokens)?;
+
+            return match tokens.next() {
+                Some(token) if token == ")" => Ok(expression),
+                Some(token) => Err(ScreenError::UnexpectedToken { token }),
+                None => Err(ScreenError::UnexpectedEnd)
+            }
+        }
+
+        // Short names of the listing like "price" or "pe" can be used as well.
+        let field = match resolve_column(&token) {
+            Ok(field) if field_kind(field).is_some() => field.to_string(),
+            _ => token.to_lowercase()
+        };
+        if field_kind(&field).is_none() {
+            return Err(ScreenError::UnknownField {
+                field,
+                available: NUMERIC_FIELDS.iter().map(|(field, _)| *field).collect::<Vec<&str>>().join(", ")
+            })
+        }
+
+        let token = tokens.next().ok_or(ScreenError::UnexpectedEnd)?;
+        let operator = Operator::parse(&token).ok_or(ScreenError::UnexpectedToken { token })?;
+
+        let token = tokens.next().ok_or(ScreenError::UnexpectedEnd)?;
+        let value = parse_amount(&token).ok_or(ScreenError::UnexpectedToken { token })?;
+
+        Ok(Expression::Comparison { field, operator, value })
+    }
+
+    pub fn print_screen(expression: &Expression, stocks: &[Stock]) {
+        if stocks.is_empty() {
+            return println!("No stock matches the screen.")
+        }
+
+        let fields = expression.fields();
+
+        let mut headers = vec!["Symbol", "Current Price"];
+        headers.extend(fields.iter().map(|field| field.as_str()));
+
+        let rows: Vec<Vec<String>> = stocks.iter()
+            .map(|stock| {
+                let mut row = vec![stock.name.to_uppercase(), stock.current_price.to_string()];
+                row.extend(fields.iter().map(|field| stock.display(field).unwrap_or_default()));
+                row
+            })
+            .collect();
+
+        print_table(&headers, &rows);
+        println!("Matching stocks: {}", stocks.len());
+    }
+}
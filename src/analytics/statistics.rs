@@ -0,0 +1,183 @@
+pub mod statistics {
+    use chrono::NaiveDate;
+    use serde::Serialize;
+
+    use crate::scraper::financial_data::get_financial_data::{historical_prices, parse_naive_date};
+
+    const TRADING_DAYS: f64 = 252.0;
+
+    #[derive(Serialize, Debug)]
+    pub struct PerformanceStatistics {
+        pub symbol: String,
+        pub from: String,
+        pub to: String,
+        pub start_price: f64,
+        pub end_price: f64,
+        pub total_return: f64,
+        pub cagr: f64,
+        pub annualised_volatility: f64,
+        pub max_drawdown: f64,
+        pub max_drawdown_peak: String,
+        pub max_drawdown_trough: String,
+        pub sharpe_ratio: f64,
+        pub sortino_ratio: f64,
+        pub risk_free_rate: f64,
+    }
+
+    pub fn daily_returns(prices: &[(NaiveDate, f64)]) -> Vec<f64> {
+        prices.windows(2)
+            .map(|pair| pair[1].1 / pair[0].1 - 1.0)
+            .collect()
+    }
+
+    pub fn mean(values: &[f64]) -> f64 {
+        if values.is_empty() {
+            return 0.0
+        }
+
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+
+    pub fn standard_deviation(values: &[f64]) -> f64 {
+        if values.len() < 2 {
+            return 0.0
+        }
+
+        let avg = mean(values);
+        let variance = values.iter()
+            .map(|value| (value - avg).powi(2))
+            .sum::<f64>() / (values.len() - 1) as f64;
+
+        variance.sqrt()
+    }
+
+    /// Risk free rate is passed in percent per year, e.g. 2.5 for 2.5%.
+    pub fn calculate(
+        symbol: &str, prices: &[(NaiveDate, f64)], risk_free_rate: f64
+    ) -> Option<PerformanceStatistics> {
+        if prices.len() < 2 {
+            return None
+        }
+
+        let (first_date, start_price) = prices[0];
+        let (last_date, end_price) = prices[prices.len() - 1];
+
+        let total_return = end_price / start_price - 1.0;
+
+        let days = (last_date - first_date).num_days() as f64;
+        let cagr = if days > 0.0 {
+            (end_price / start_price).powf(365.25 / days) - 1.0
+        } else {
+            0.0
+        };
+
+        let returns = daily_returns(prices);
+        let daily_risk_free = risk_free_rate / 100.0 / TRADING_DAYS;
+        let excess_returns: Vec<f64> = returns.iter().map(|r| r - daily_risk_free).collect();
+
+        let daily_volatility = standard_deviation(&returns);
+        let annualised_volatility = daily_volatility * TRADING_DAYS.sqrt();
+
+        let sharpe_ratio = if daily_volatility > 0.0 {
+            mean(&excess_returns) / daily_volatility * TRADING_DAYS.sqrt()
+        } else {
+            0.0
+        };
+
+        let downside_deviation = (excess_returns.iter()
+            .map(|r| r.min(0.0).powi(2))
+            .sum::<f64>() / excess_returns.len() as f64).sqrt();
+
+        let sortino_ratio = if downside_deviation > 0.0 {
+            mean(&excess_returns) / downside_deviation * TRADING_DAYS.sqrt()
+        } else {
+            0.0
+        };
+
+        let mut peak = prices[0];
+        let mut max_drawdown = 0.0;
+        let mut max_drawdown_peak = first_date;
+        let mut max_drawdown_trough = first_date;
+
+        for &(date, price) in prices {
+            if price > peak.1 {
+                peak = (date, price);
+            }
+
+            let drawdown = price / peak.1 - 1.0;
+            if drawdown < max_drawdown {
+                max_drawdown = drawdown;
+                max_drawdown_peak = peak.0;
+                max_drawdown_trough = date;
+            }
+        }
+
+        Some(PerformanceStatistics {
+            symbol: symbol.to_uppercase(),
+            from: first_date.format("%d.%m.%Y").to_string(),
+            to: last_date.format("%d.%m.%Y").to_string(),
+            start_price,
+            end_price,
+            total_return: total_return * 100.0,
+            cagr: cagr * 100.0,
+            annualised_volatility: annualised_volatility * 100.0,
+            max_drawdown: max_drawdown * 100.0,
+            max_drawdown_peak: max_drawdown_peak.format("%d.%m.%Y").to_string(),
+            max_drawdown_trough: max_drawdown_trough.format("%d.%m.%Y").to_string(),
+            sharpe_ratio,
+            sortino_ratio,
+            risk_free_rate,
+        })
+    }
+
+    pub async fn print_stats(symbol: String, from: String, to: Option<String>, risk_free_rate: f64, json: bool) {
+        let from_date = match parse_naive_date(from) {
+            Ok(date) => date,
+            Err(e) => return println!("{}", e)
+        };
+
+        let to_date = match to {
+            Some(to) => match parse_naive_date(to) {
+                Ok(date) => date,
+                Err(e) => return println!("{}", e)
+            },
+            None => chrono::Local::now().date_naive()
+        };
+
+        if from_date >= to_date {
+            return println!("The start date needs to lie before the end date.")
+        }
+
+        let prices = match historical_prices(symbol.as_str(), from_date, to_date).await {
+            Ok(prices) => prices,
+            Err(e) => return println!("{}", e)
+        };
+
+        let stats = match calculate(symbol.as_str(), &prices, risk_free_rate) {
+            Some(stats) => stats,
+            None => return println!("Not enough prices between these dates to calculate statistics.")
+        };
+
+        if json {
+            match serde_json::to_string_pretty(&stats) {
+                Ok(output) => println!("{}", output),
+                Err(e) => println!("Error occurred when serializing statistics: {}", e)
+            }
+        } else {
+            println!("Stock: {}", stats.symbol);
+            println!("Period: {} - {}", stats.from, stats.to);
+            println!("  - Start Price: {:.2}", stats.start_price);
+            println!("  - End Price: {:.2}", stats.end_price);
+            println!("  - Total Return: {:.2}%", stats.total_return);
+            println!("  - CAGR: {:.2}%", stats.cagr);
+            println!("  - Annualised Volatility: {:.2}%", stats.annualised_volatility);
+            println!(
+                "  - Max Drawdown: {:.2}% ({} - {})", 
+                stats.max_drawdown, stats.max_drawdown_peak, stats.max_drawdown_trough
+            );
+            println!("  - Sharpe Ratio: {:.2}", stats.sharpe_ratio);
+            println!("  - Sortino Ratio: {:.2}", stats.sortino_ratio);
+            println!("  - Risk Free Rate: {:.2}%", stats.risk_free_rate);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+pub mod watch {
+    use std::{collections::HashSet, time::Duration};
+
+    use chrono::prelude::*;
+    use chrono_tz::America::New_York;
+    use tokio::time::MissedTickBehavior;
+
+    use crate::analytics::alerts::alerts::{Alert, print_newly_triggered, skip_alerts};
+    use crate::database::queries::queries::Stock;
+
+    /// Parses an interval like "90s", "15m" or "1h".
+    pub fn parse_every(every: &str) -> Option<Duration> {
+        let every = every.trim().to_lowercase();
+        let unit = every.chars().last()?;
+        let number = every[..every.len() - unit.len_utf8()].parse::<u64>().ok()?;
+
+        let seconds = match unit {
+            's' => Some(number),
+            'm' => number.checked_mul(60),
+            'h' => number.checked_mul(60 * 60),
+            _ => return None
+        };
+
+        match seconds? {
+            0 => None,
+            seconds => Some(Duration::from_secs(seconds))
+        }
+    }
+
+    /// Whether the US exchanges are open, from 9:30 to 16:00 New York time on weekdays. 
+    /// Holidays aren't taken into account.
+    pub fn market_open(now: DateTime<Utc>) -> bool {
+        let new_york = now.with_timezone(&New_York);
+        let minutes = new_york.hour() * 60 + new_york.minute();
+
+        new_york.weekday().number_from_monday() <= 5 && (9 * 60 + 30..16 * 60).contains(&minutes)
+    }
+
+    /// Prints the message with the current time.
+    pub fn log(message: &str) {
+        println!("[{}] {}", Local::now().format("%d.%m.%Y %H:%M:%S"), message);
+    }
+
+    /// Updates all stocks on every tick and prints the alerts which triggered since the previous
+    /// tick. The backend passes in its calls, which get `backend` as their state: `update_all`
+    /// returns the symbols which couldn't be updated, whose alerts aren't checked.
+    pub async fn watch<B>(
+        backend: &mut B,
+        every: &str,
+        market_hours_only: bool,
+        mut update_all: impl AsyncFnMut(&mut B) -> Result<Vec<String>, String>,
+        mut alerts: impl AsyncFnMut(&mut B) -> Result<Vec<Alert>, String>,
+        mut stocks: impl AsyncFnMut(&mut B) -> Result<Vec<Stock>, String>,
+    ) {
+        let interval = match parse_every(every) {
+            Some(interval) => interval,
+            None => return println!("Interval {} could not be read. Use e.g. 90s, 15m or 1h.", every)
+        };
+
+        log(&format!("Watching your stocks every {}. Press Ctrl+C to stop.", every));
+
+        // Ticks keep their spacing however long an update takes, and a late tick
+        // delays the following ones instead of firing them all at once.
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let mut triggered_before = HashSet::new();
+
+        loop {
+            ticker.tick().await;
+
+            if market_hours_only && !market_open(Utc::now()) {
+                log("Market is closed, update was skipped.");
+                continue
+            }
+
+            log("Updating stocks...");
+            let failed = match update_all(backend).await {
+                Ok(failed) => failed,
+                Err(e) => {
+                    log(&format!("Error: {}", e));
+                    continue
+                }
+            };
+
+            let mut alerts = match alerts(backend).await {
+                Ok(alerts) if alerts.is_empty() => continue,
+                Ok(alerts) => alerts,
+                Err(e) => {
+                    log(&format!("Error: {}", e));
+                    continue
+                }
+            };
+
+            log("Checking alerts...");
+            for symbol in &failed {
+                skip_alerts(&mut alerts, symbol);
+            }
+
+            match stocks(backend).await {
+                Ok(stocks) => print_newly_triggered(&alerts, &stocks, &mut triggered_before),
+                Err(e) => log(&format!("Error: {}", e))
+            }
+        }
+    }
+}
@@ -1,3 +1,7 @@
 pub mod queries;
 pub mod cmd;
-pub mod database;
\ No newline at end of file
+pub mod database;
+pub mod transactions;
+pub mod watchlists;
+pub mod statements;
+pub mod alerts;
\ No newline at end of file
@@ -0,0 +1,27 @@
+pub mod alerts {
+    use tokio_postgres::{Client, Error, types::Type};
+
+    use crate::analytics::alerts::alerts::Alert;
+
+    /// Saves the alert and returns its id.
+    pub async fn insert(client: &mut Client, symbol: &str, condition: &str) -> Result<i32, Error> {
+        let statement = client.prepare_typed(
+            "INSERT INTO alerts (SYMBOL, CONDITION) VALUES ($1, $2) RETURNING id",
+            &[Type::VARCHAR, Type::VARCHAR],
+        ).await?;
+
+        Ok(client.query_one(&statement, &[&symbol, &condition]).await?.get(0))
+    }
+
+    pub async fn delete(client: &mut Client, id: i32) -> Result<u64, Error> {
+        client.execute("DELETE FROM alerts WHERE id = $1", &[&id]).await
+    }
+
+    pub async fn list(client: &mut Client) -> Result<Vec<Alert>, Error> {
+        let rows = client.query("SELECT id, symbol, condition FROM alerts ORDER BY id", &[]).await?;
+
+        Ok(rows.iter()
+            .map(|row| Alert { id: row.get(0), symbol: row.get(1), condition: row.get(2) })
+            .collect())
+    }
+}
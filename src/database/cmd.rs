@@ -1,66 +1,498 @@
 pub mod cmd {
+    use std::collections::HashMap;
+
+    use chrono::{Datelike, Local};
     use tokio_postgres::{Client, Error};
 
-    use crate::database::database::database::{read_database_url, set_database_url};
-    use crate::fundamentals::explanations::print_expl;
-    use crate::{Opt, Command, init_mode};
+    use crate::database::database::database::{read_database_url, set_database_url, apply_schema};
+    use crate::fundamentals::glossary::glossary::{print_expl, print_search};
+    use crate::fundamentals::evaluation::evaluation::print_evaluation;
+    use crate::analytics::statistics::statistics::print_stats;
+    use crate::analytics::compare::compare::print_comparison;
+    use crate::analytics::peers::peers::print_peers;
+    use crate::analytics::alerts::alerts::{TRIGGERED_EXIT_CODE, symbols, print_alerts, print_triggered, skip_alerts};
+    use crate::analytics::watch::watch::watch;
+    use crate::output::listing::listing::print_stocks;
+    use crate::output::locale::locale::{Locale, current_locale, set_locale};
+    use crate::errors::error_handler::error_handler::LocaleError;
+    use crate::statements::statements::statements::{Frequency, Kind as StatementKind, print_statement};
+    use crate::statements::ratios::ratios::print_ratios;
+    use crate::statements::scores::scores::print_scores;
+    use crate::scraper::timeseries::timeseries::fetch_statement;
+    use crate::valuation::dcf::dcf::{Assumptions, print_dcf};
+    use crate::valuation::graham::graham::print_value;
+    use crate::database::queries::queries::{Stock, parse_amount};
+    use crate::analytics::screener::screener::{Expression, print_screen};
+    use crate::analytics::benchmark::benchmark::{read_benchmark, set_benchmark, print_benchmark};
+    use crate::{Opt, Command, AlertCommand, ListCommand, PortfolioCommand, TargetCommand, init_mode};
     use crate::database::queries::queries as database_query;
-    use crate::scraper::financial_data::get_financial_data::{self as stock_scraper, split_date, print_history_price};
+    use crate::database::transactions::transactions as transaction_query;
+    use crate::database::watchlists::watchlists as watchlist_query;
+    use crate::database::alerts::alerts as alert_query;
+    use crate::database::statements::statements as statement_query;
+    use crate::portfolio::transactions::transactions::{Kind, Transaction, validate};
+    use crate::portfolio::positions::positions::print_portfolio;
+    use crate::portfolio::gains::gains::{Method, print_gains};
+    use crate::portfolio::dividends::dividends::print_dividends;
+    use crate::portfolio::returns::returns::print_returns;
+    use crate::portfolio::allocation::allocation::{Dimension, print_allocation};
+    use crate::portfolio::rebalance::rebalance::{read_targets, set_target, print_targets, print_rebalance};
+    use crate::portfolio::import::import::{Status, read_format, read_transactions, unknown_symbols, reject_symbol, print_preview};
+    use crate::scraper::financial_data::get_financial_data::{self as stock_scraper, split_date, parse_period, print_history_price};
 
     pub async fn run(opt: Opt, mut client: Client) -> Result<(), Error> {
         match opt.cmd {
-            Command::Add { stock_name } => {
+            Command::Add { stock_name, list } => {
+                if let Some(list) = &list {
+                    if !watchlist_query::exists(&mut client, list.to_lowercase()).await? {
+                        println!("List {} does not exist. Run 'list create {}' first.", list, list);
+                        return Ok(())
+                    }
+                }
+
                 let stock_already_exists = database_query::stock_exists(
                     &mut client, 
                     stock_name.to_lowercase()
                 ).await;
+
+                let mut stock_available = stock_already_exists;
     
                 if stock_already_exists {
-                    println!("Stock already exists")
+                    if list.is_none() {
+                        println!("Stock already exists")
+                    }
                 } else {
-                    let url = stock_scraper::Url { symbol: stock_name.clone() };
-    
-                    let selector = ::scraper::Selector::parse("section[id='lookup-page']");
-    
-                    match selector {
-                        Ok(selector_parse) => {
-                            let uri = url.scrapped_home().await;
-                            let html = uri.select(&selector_parse).next();
-            
-                            if html == None {
-                                match database_query::insert(&mut client, stock_name).await {
-                                    Ok(_) => println!("Stock was added"),
-                                    Err(e) => println!("Error: {}", e)
-                                };
-                            } else {
-                                println!("Stock symbol is not valid. Make sure that it exists.");
+                    stock_available = add_stock(&mut client, stock_name.clone()).await;
+                }
+
+                if let (Some(list), true) = (list, stock_available) {
+                    match watchlist_query::add_stock(&mut client, list.to_lowercase(), stock_name.to_lowercase()).await? {
+                        0 => println!("Stock is already in list {}", list),
+                        _ => println!("Stock was added to list {}", list)
+                    }
+                }
+            }
+            Command::List { action, list, columns, sort, desc, limit } => {
+                match action {
+                    Some(ListCommand::Create { name }) => {
+                        if watchlist_query::exists(&mut client, name.to_lowercase()).await? {
+                            println!("List {} already exists", name)
+                        } else {
+                            watchlist_query::create(&mut client, name.to_lowercase()).await?;
+                            println!("List {} was created", name)
+                        }
+                    },
+                    Some(ListCommand::Delete { name }) => {
+                        match watchlist_query::delete(&mut client, name.to_lowercase()).await? {
+                            0 => println!("List {} could not be found", name),
+                            _ => println!("List {} was deleted", name)
+                        }
+                    },
+                    Some(ListCommand::Show {}) => {
+                        for (name, stocks) in watchlist_query::all(&mut client).await? {
+                            println!("{} ({}): {}", name, stocks.len(), stocks.join(", ").to_uppercase());
+                        }
+                    },
+                    None => {
+                        if let Some(list) = &list {
+                            if !watchlist_query::exists(&mut client, list.to_lowercase()).await? {
+                                println!("List {} could not be found", list);
+                                return Ok(())
                             }
-                        },
-                        Err(e) => println!("Error occurred when trying to parse page: {:?}", e)
+                        }
+
+                        let stocks = database_query::list(&mut client, list.map(|list| list.to_lowercase())).await?;
+
+                        print_stocks(stocks, columns, sort, desc, limit)
                     }
                 }
             }
-            Command::List {} => {
-                match database_query::list(&mut client).await {
-                    Err(e) => println!("failed: {}", e),
-                    _ => (),
+            Command::Peers { stock_names } => {
+                let mut stocks: Vec<Stock> = Vec::new();
+
+                for stock_name in stock_names {
+                    let stock_name = stock_name.to_lowercase();
+
+                    if stocks.iter().any(|stock| stock.name == stock_name) {
+                        continue
+                    }
+
+                    if !database_query::stock_exists(&mut client, stock_name.clone()).await && !add_stock(&mut client, stock_name.clone()).await {
+                        continue
+                    }
+
+                    stocks.push(database_query::search(&mut client, stock_name, false).await?);
                 }
+
+                print_peers(&stocks)
             }
-            Command::Info { explanation } => {
-                let expl = explanation.to_lowercase();
-    
-                print_expl(expl)
+            Command::Alert { action } => {
+                match action {
+                    AlertCommand::Add { stock_name, condition } => {
+                        let condition = condition.join(" ");
+                        if let Err(e) = Expression::parse(&condition) {
+                            println!("{}", e);
+                            return Ok(())
+                        }
+
+                        let stock_name = stock_name.to_lowercase();
+                        if !database_query::stock_exists(&mut client, stock_name.clone()).await && !add_stock(&mut client, stock_name.clone()).await {
+                            return Ok(())
+                        }
+
+                        let id = alert_query::insert(&mut client, &stock_name, &condition).await?;
+                        println!("Alert {} was added!", id)
+                    },
+                    AlertCommand::Remove { id } => {
+                        match alert_query::delete(&mut client, id).await? {
+                            0 => println!("Alert {} could not be found", id),
+                            _ => println!("Alert {} was removed", id)
+                        }
+                    },
+                    AlertCommand::List {} => {
+                        print_alerts(&alert_query::list(&mut client).await?)
+                    },
+                    AlertCommand::Check {} => {
+                        let mut alerts = alert_query::list(&mut client).await?;
+                        if alerts.is_empty() {
+                            print_alerts(&alerts);
+                            return Ok(())
+                        }
+
+                        for symbol in symbols(&alerts) {
+                            match database_query::update(&mut client, symbol.clone()).await {
+                                Ok(1) => println!("Stock updated: {}", symbol),
+                                Ok(_) => {
+                                    println!("Stock could not be updated: {}", symbol);
+                                    skip_alerts(&mut alerts, &symbol)
+                                },
+                                Err(e) => {
+                                    println!("Error: {}.", e);
+                                    skip_alerts(&mut alerts, &symbol)
+                                }
+                            }
+                        }
+                        println!();
+
+                        let stocks = database_query::list(&mut client, None).await?;
+
+                        if print_triggered(&alerts, &stocks) {
+                            std::process::exit(TRIGGERED_EXIT_CODE)
+                        }
+                    }
+                }
+            }
+            Command::Buy { stock_name, quantity, price, date, fee } => {
+                record_transaction(&mut client, Kind::Buy, stock_name, quantity, price, date, fee).await?
+            }
+            Command::Sell { stock_name, quantity, price, date, fee } => {
+                record_transaction(&mut client, Kind::Sell, stock_name, quantity, price, date, fee).await?
+            }
+            Command::Portfolio { view } => {
+                let transactions = transaction_query::list(&mut client).await?;
+
+                match view {
+                    Some(PortfolioCommand::Returns { since, to }) => {
+                        let (from, to) = match parse_period(since, to) {
+                            Ok(period) => period,
+                            Err(e) => {
+                                println!("{}", e);
+                                return Ok(())
+                            }
+                        };
+
+                        print_returns(&transactions, from, to).await
+                    },
+                    Some(PortfolioCommand::Allocation { by, warn_above }) => {
+                        let dimension = match Dimension::parse(&by) {
+                            Some(dimension) => dimension,
+                            None => {
+                                println!("Allocation by {} is not supported. Use sector, industry, country or currency.", by);
+                                return Ok(())
+                            }
+                        };
+
+                        let prices = database_query::current_prices(&mut client).await?;
+                        let classifications = database_query::classifications(&mut client).await?;
+
+                        print_allocation(&transactions, &prices, &classifications, dimension, warn_above)
+                    },
+                    None => {
+                        let prices = database_query::current_prices(&mut client).await?;
+
+                        print_portfolio(&transactions, &prices)
+                    }
+                }
+            }
+            Command::ImportTransactions { csv, format, dry_run } => {
+                let broker_format = match read_format(&format) {
+                    Ok(broker_format) => broker_format,
+                    Err(e) => {
+                        println!("{}", e);
+                        return Ok(())
+                    }
+                };
+
+                let transactions = transaction_query::list(&mut client).await?;
+
+                let mut rows = match read_transactions(&csv, &broker_format, &transactions) {
+                    Ok(rows) => rows,
+                    Err(e) => {
+                        println!("{}", e);
+                        return Ok(())
+                    }
+                };
+
+                let known: Vec<String> = database_query::current_prices(&mut client).await?.into_keys().collect();
+                let unknown = unknown_symbols(&rows, &known);
+
+                print_preview(&rows, &unknown);
+
+                if dry_run {
+                    println!("Dry run, nothing was imported.");
+                    return Ok(())
+                }
+
+                for symbol in unknown {
+                    if !add_stock(&mut client, symbol.clone()).await {
+                        reject_symbol(&mut rows, &symbol);
+                        println!("Transactions of {} are skipped.", symbol.to_uppercase());
+                    }
+                }
+
+                let mut imported = 0;
+                for row in rows.iter().filter(|row| row.status == Status::New) {
+                    if let Some(transaction) = &row.transaction {
+                        imported += transaction_query::insert(&mut client, transaction).await?;
+                    }
+                }
+
+                println!("{} transactions were imported!", imported);
+            }
+            Command::Dividends { forecast } => {
+                let transactions = transaction_query::list(&mut client).await?;
+
+                print_dividends(&transactions, forecast).await
+            }
+            Command::Gains { year, method } => {
+                let method = match Method::parse(&method) {
+                    Some(method) => method,
+                    None => {
+                        println!("Method {} is not supported. Use fifo, lifo or average.", method);
+                        return Ok(())
+                    }
+                };
+
+                let transactions = transaction_query::list(&mut client).await?;
+
+                print_gains(&transactions, year.unwrap_or_else(|| Local::now().year()), method)
+            }
+            Command::Benchmark { since, to, list } => {
+                let benchmark = match read_benchmark() {
+                    Ok(benchmark) => benchmark,
+                    Err(e) => {
+                        println!("{}", e);
+                        return Ok(())
+                    }
+                };
+
+                let (from, to) = match parse_period(since, to) {
+                    Ok(period) => period,
+                    Err(e) => {
+                        println!("{}", e);
+                        return Ok(())
+                    }
+                };
+
+                let symbols = match list {
+                    Some(list) => watchlist_query::stocks(&mut client, list.to_lowercase()).await?,
+                    None => database_query::current_prices(&mut client).await?.into_keys().collect()
+                };
+                let transactions = transaction_query::list(&mut client).await?;
+
+                print_benchmark(benchmark, symbols, &transactions, from, to).await
+            }
+            Command::SetBenchmark { symbol } => {
+                match set_benchmark(symbol) {
+                    Ok(_) => println!("Benchmark was set!"),
+                    Err(e) => println!("Error occurred when trying to set benchmark: {}", e)
+                }
+            }
+            Command::SetLocale { locale } => {
+                match Locale::parse(&locale) {
+                    Some(locale) => match set_locale(locale) {
+                        Ok(_) => println!("Locale was set!"),
+                        Err(e) => println!("Error occurred when trying to set locale: {}", e)
+                    },
+                    None => println!("{}", LocaleError::UnknownLocale { locale })
+                }
+            }
+            Command::Screen { expression } => {
+                let expression = match Expression::parse(&expression) {
+                    Ok(expression) => expression,
+                    Err(e) => {
+                        println!("{}", e);
+                        return Ok(())
+                    }
+                };
+
+                let mut params = Vec::new();
+                let condition = expression.to_sql(&mut params);
+                let stocks = database_query::screen(&mut client, &condition, &params).await?;
+
+                print_screen(&expression, &stocks)
+            }
+            Command::Dcf { stock_name, fcf, shares, growth, years, terminal_growth, discount_rate } => {
+                let (fcf, shares) = match (
+                    fcf.as_deref().map(parse_amount), 
+                    shares.as_deref().map(parse_amount)
+                ) {
+                    (Some(None), _) | (_, Some(None)) => {
+                        println!("Free cash flow and shares need to be numbers like 100000000 or 100M.");
+                        return Ok(())
+                    },
+                    (fcf, shares) => (fcf.flatten(), shares.flatten())
+                };
+
+                let stock = database_query::search(&mut client, stock_name.to_lowercase(), false).await?;
+                if stock.name.is_empty() {
+                    return Ok(())
+                }
+                let assumptions = Assumptions { growth, years, terminal_growth, discount_rate };
+
+                print_dcf(&stock, fcf, shares, assumptions).await
+            }
+            Command::Financials { stock_name, statement, quarterly, update } => {
+                let kind = match StatementKind::parse(&statement) {
+                    Some(kind) => kind,
+                    None => {
+                        println!("Statement {} is not supported. Use income, balance or cashflow.", statement);
+                        return Ok(())
+                    }
+                };
+                let frequency = if quarterly { Frequency::Quarterly } else { Frequency::Annual };
+                let symbol = stock_name.to_lowercase();
+
+                let mut rows = statement_query::list(&mut client, &symbol, kind, frequency).await?;
+
+                if rows.is_empty() || update {
+                    rows = match fetch_statement(&symbol, kind, frequency).await {
+                        Ok(rows) => rows,
+                        Err(e) => {
+                            println!("{}", e);
+                            return Ok(())
+                        }
+                    };
+
+                    statement_query::save(&mut client, &symbol, kind, frequency, &rows).await?;
+                }
+
+                print_statement(&symbol, kind, frequency, &rows)
+            }
+            Command::Score { stock_name, update } => {
+                let symbol = stock_name.to_lowercase();
+
+                for kind in [StatementKind::Income, StatementKind::Balance, StatementKind::CashFlow] {
+                    if update || statement_query::list(&mut client, &symbol, kind, Frequency::Annual).await?.is_empty() {
+                        match fetch_statement(&symbol, kind, Frequency::Annual).await {
+                            Ok(rows) => { statement_query::save(&mut client, &symbol, kind, Frequency::Annual, &rows).await?; },
+                            Err(e) => println!("{}", e)
+                        }
+                    }
+                }
+
+                let rows = statement_query::for_symbol(&mut client, &symbol, Frequency::Annual).await?;
+                let price = database_query::current_prices(&mut client).await?.get(&symbol).copied();
+
+                print_scores(&symbol, &rows, price)
+            }
+            Command::Value { stock_name, growth, bond_yield } => {
+                let stock = database_query::search(&mut client, stock_name.to_lowercase(), false).await?;
+                if stock.name.is_empty() {
+                    return Ok(())
+                }
+
+                print_value(&stock, growth, bond_yield)
+            }
+            Command::Target { action } => {
+                match action {
+                    TargetCommand::Show {} => match read_targets() {
+                        Ok(targets) => print_targets(&targets),
+                        Err(e) => println!("{}", e)
+                    },
+                    TargetCommand::Set { name, weight, tag } => {
+                        if !(0.0..=100.0).contains(&weight) {
+                            println!("Weight has to be between 0 and 100.");
+                            return Ok(())
+                        }
+
+                        match set_target(name.to_lowercase(), Some(weight), tag) {
+                            Ok(_) => println!("Target was set!"),
+                            Err(e) => println!("Error occurred when trying to set target: {}", e)
+                        }
+                    },
+                    TargetCommand::Remove { name, tag } => {
+                        match set_target(name.to_lowercase(), None, tag) {
+                            Ok(_) => println!("Target was removed!"),
+                            Err(e) => println!("Error occurred when trying to remove target: {}", e)
+                        }
+                    }
+                }
+            }
+            Command::Rebalance { cash, min_trade, whole_shares } => {
+                let transactions = transaction_query::list(&mut client).await?;
+                let prices = database_query::current_prices(&mut client).await?;
+                let tags: HashMap<String, Vec<String>> = watchlist_query::all(&mut client).await?.into_iter().collect();
+
+                print_rebalance(&transactions, &prices, &tags, cash, min_trade, whole_shares)
+            }
+            Command::Info { explanation, stock_name, search } => {
+                if let Some(query) = search {
+                    print_search(&query, current_locale());
+                    return Ok(())
+                }
+
+                let term = match print_expl(&explanation, current_locale()) {
+                    Some(term) => term,
+                    None => return Ok(())
+                };
+
+                if let Some(stock_name) = stock_name {
+                    let stock = database_query::search(&mut client, stock_name.to_lowercase(), false).await?;
+
+                    if !stock.name.is_empty() {
+                        let rows = statement_query::for_symbol(&mut client, &stock.name, Frequency::Annual).await?;
+
+                        print_evaluation(&term, &stock, &rows)
+                    }
+                }
             }
             Command::Search { stock_name } => {
                 match database_query::search(&mut client, stock_name.to_lowercase(), true).await {
-                    Err(e) => println!("Error occurred when searching for stock: {}", e),
-                    _ => (),
+                    Ok(stock) if !stock.name.is_empty() => {
+                        let rows = statement_query::for_symbol(&mut client, &stock_name.to_lowercase(), Frequency::Annual).await?;
+
+                        println!();
+                        print_ratios(&rows, current_locale())
+                    },
+                    Ok(_) => {},
+                    Err(e) => println!("Error occurred when searching for stock: {}", e)
                 }
             }
-            Command::Drop { stock_name } => {
-                match database_query::drop(&mut client, stock_name).await {
+            Command::Drop { stock_name, list } => {
+                if let Some(list) = list {
+                    match watchlist_query::remove_stock(&mut client, list.to_lowercase(), stock_name.to_lowercase()).await? {
+                        0 => println!("Stock could not be found in list {}", list),
+                        _ => println!("Stock was removed from list {}", list)
+                    }
+
+                    return Ok(())
+                }
+
+                match database_query::drop(&mut client, stock_name.clone()).await {
                     Ok(result) => {
                         if result == 1 {
+                            watchlist_query::remove_stock_from_all(&mut client, stock_name.to_lowercase()).await?;
                             println!("Stock was deleted")
                         } else if result == 0 {
                             println!("Stock could not be found")
@@ -75,19 +507,43 @@ pub mod cmd {
                     _ => println!("Stock was updated")
                 }
             }
-            Command::UpdateAll {} => {
+            Command::UpdateAll { list } => {
                 println!("This may take a while...");
     
-                database_query::update_all(&mut client).await;
+                if let Err(e) = database_query::update_all(&mut client, list.map(|list| list.to_lowercase())).await {
+                    println!("Error: {}", e)
+                }
             }
-            Command::History { stock_name, date } => {
+            Command::Watch { every, market_hours_only } => {
+                watch(
+                    &mut client,
+                    &every,
+                    market_hours_only,
+                    async |client: &mut Client| database_query::update_all(client, None).await.map_err(|e| e.to_string()),
+                    async |client: &mut Client| alert_query::list(client).await.map_err(|e| e.to_string()),
+                    async |client: &mut Client| database_query::list(client, None).await.map_err(|e| e.to_string()),
+                ).await
+            }
+            Command::History { stock_name, date, interval } => {
                 let stock = database_query::search(&mut client, stock_name.clone(), false);
     
-                let splitted_date = split_date(date);
+                let splitted_date = match split_date(date) {
+                    Ok(splitted_date) => splitted_date,
+                    Err(e) => {
+                        println!("{}", e);
+                        return Ok(())
+                    }
+                };
 
                 let current_price = stock.await.unwrap().current_price;
 
-                print_history_price(stock_name, splitted_date, current_price).await
+                print_history_price(stock_name, splitted_date, current_price, interval, current_locale()).await
+            }
+            Command::Stats { stock_name, from, to, risk_free, json } => {
+                print_stats(stock_name, from, to, risk_free, json).await
+            }
+            Command::Compare { stock_names, since, normalise } => {
+                print_comparison(stock_names, since, normalise).await
             }
             Command::Init {} => {
                 init_mode().await
@@ -110,6 +566,11 @@ pub mod cmd {
                     }
                 }
             }
+            Command::Migrate {} => {
+                apply_schema(&client, false).await;
+
+                println!("Database schema is up to date!")
+            }
             Command::Mode {} => {
                 println!("Database")
             }
@@ -118,6 +579,78 @@ pub mod cmd {
         Ok(())
     }
 
+    /// Validates the symbol against Yahoo's lookup page and saves the stock.
+    async fn add_stock(client: &mut Client, stock_name: String) -> bool {
+        let url = stock_scraper::Url { symbol: stock_name.clone() };
+
+        let selector = ::scraper::Selector::parse("section[id='lookup-page']");
+
+        match selector {
+            Ok(selector_parse) => {
+                let uri = match url.scrapped_home().await {
+                    Ok(uri) => uri,
+                    Err(e) => {
+                        println!("{}", e);
+                        return false
+                    }
+                };
+                let html = uri.select(&selector_parse).next();
+
+                if html.is_none() {
+                    match database_query::insert(client, stock_name).await {
+                        Ok(_) => {
+                            println!("Stock was added");
+                            return true
+                        },
+                        Err(e) => println!("Error: {}", e)
+                    };
+                } else {
+                    println!("Stock symbol is not valid. Make sure that it exists.");
+                }
+            },
+            Err(e) => println!("Error occurred when trying to parse page: {:?}", e)
+        }
+
+        false
+    }
+
+    async fn record_transaction(
+        client: &mut Client, 
+        kind: Kind, 
+        stock_name: String, 
+        quantity: f64, 
+        price: f64, 
+        date: Option<String>, 
+        fee: f64
+    ) -> Result<(), Error> {
+        let transaction = match Transaction::new(kind, stock_name, quantity, price, date, fee) {
+            Ok(transaction) => transaction,
+            Err(e) => {
+                println!("{}", e);
+                return Ok(())
+            }
+        };
+
+        let transactions = transaction_query::list(client).await?;
+
+        if let Err(e) = validate(&transactions, &transaction) {
+            println!("{}", e);
+            return Ok(())
+        }
+
+        transaction_query::insert(client, &transaction).await?;
+        println!("Transaction was recorded!");
+
+        if !database_query::stock_exists(client, transaction.symbol.clone()).await {
+            println!(
+                "Stock {} is not in your stocks yet. Run 'add {}' so the portfolio can use its current price.", 
+                transaction.symbol.to_uppercase(), transaction.symbol
+            );
+        }
+
+        Ok(())
+    }
+
     pub async fn fail_safe(opt: Opt) -> Result<(), Error> {
         match opt.cmd {
             Command::Init {} => {
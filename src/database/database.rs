@@ -4,7 +4,7 @@ pub mod database {
         io::{BufWriter, BufReader, BufRead, Write}
     };
 
-    use tokio_postgres::{Error, NoTls};
+    use tokio_postgres::{Client, Error, NoTls};
 
     use crate::errors::error_handler::error_handler as error;
     
@@ -30,7 +30,7 @@ pub mod database {
             .open("config/database.yml")
             .expect("Couldn't open file");
     
-        if url == "" {
+        if url.is_empty() {
             url = format!("database_url: postgresql://{}:{}@{}:{}/", user_name, pw, host, port);
         } else {
             url = format!("database_url: {}", url);
@@ -50,8 +50,8 @@ pub mod database {
         let pw = rpassword::prompt_password("Enter your postgres password").unwrap();
         let mut port = rpassword::prompt_password("Enter port. Default is 5433. Leave blank if default and press Enter").unwrap();
     
-        host = if host == "" { "localhost".to_string() } else { host };
-        port = if port == "" { "5433".to_string() } else { port };
+        host = if host.is_empty() { "localhost".to_string() } else { host };
+        port = if port.is_empty() { "5433".to_string() } else { port };
     
         match set_database_url(
             user_name.clone(), 
@@ -72,19 +72,33 @@ pub mod database {
     
                 println!("Creating database...");
                 
-                let file = File::open("config/schema.sql").unwrap();
-                let reader = BufReader::new(file);
-    
-                for line in reader.lines().enumerate() {
-                    match client.batch_execute(line.1.unwrap().as_str()).await {
-                        Err(e) => println!("error: {}", e),
-                        _ => (),
-                    }
-                }
+                apply_schema(&client, true).await;
     
                 Ok(())
             },
-            Err(e) => Ok(println!("{}", e))
+            Err(e) => {
+                println!("{}", e);
+                Ok(())
+            }
+        }
+    }
+
+    /// Runs every statement of config/schema.sql. Statements are idempotent, so this is 
+    /// also used to add tables and columns of newer versions to an existing database.
+    pub async fn apply_schema(client: &Client, create_database: bool) {
+        let file = File::open("config/schema.sql").unwrap();
+        let reader = BufReader::new(file);
+
+        for line in reader.lines() {
+            let statement = line.unwrap();
+
+            if !create_database && statement.starts_with("CREATE DATABASE") {
+                continue
+            }
+
+            if let Err(e) = client.batch_execute(statement.as_str()).await {
+                println!("error: {}", e)
+            }
         }
     }
 }
\ No newline at end of file
@@ -1,26 +1,36 @@
 pub mod queries {
-    use tokio_postgres::{Client, Error, types::Type};
+    use std::{collections::HashMap, sync::OnceLock};
 
+    use regex::Regex;
+    use tokio_postgres::{Client, Error, Row, types::{ToSql, Type}};
+
+    use crate::errors::error_handler::error_handler::StockError;
+    use crate::portfolio::allocation::allocation::Classification;
+    use crate::output::locale::locale::{Locale, current_locale};
     use crate::scraper::financial_data::get_financial_data as stock_scraper;
 
     #[derive(Debug, Clone)]
     pub struct Stock {
         pub name: String,
         pub current_price: f64,
-        eps_ttm: f64,
-        pe_ratio: f64,
-        total_debt_equity: f64,
-        change_since: String,
-        market_cap: String,
-        peg_ratio: f64,
-        price_to_book: f64,
-        revenue: String,
-        gross_profit: String,
-        total_cash: String,
-        total_debt: String,
-        return_on_equity: String,
-        return_on_assets: String,
-        bvps: f64,
+        pub eps_ttm: f64,
+        pub pe_ratio: f64,
+        pub total_debt_equity: f64,
+        pub change_since: String,
+        pub market_cap: String,
+        pub peg_ratio: f64,
+        pub price_to_book: f64,
+        pub revenue: String,
+        pub gross_profit: String,
+        pub total_cash: String,
+        pub total_debt: String,
+        pub return_on_equity: String,
+        pub return_on_assets: String,
+        pub bvps: f64,
+        pub sector: String,
+        pub industry: String,
+        pub country: String,
+        pub currency: String,
     }
 
     impl Default for Stock {
@@ -30,50 +40,221 @@ pub mod queries {
                 total_debt_equity: 0.0, change_since: "".to_string(), market_cap: "".to_string(),
                 peg_ratio: 0.0, price_to_book: 0.0, revenue: "".to_string(), gross_profit: "".to_string(),
                 total_cash: "".to_string(), total_debt: "".to_string(), return_on_equity: "".to_string(),
-                return_on_assets: "".to_string(), bvps: 0.0,
+                return_on_assets: "".to_string(), bvps: 0.0, sector: "".to_string(),
+                industry: "".to_string(), country: "".to_string(), currency: "".to_string(),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum FieldKind {
+        Number,
+        Amount,
+    }
+
+    /// Numeric fields of a stock which can be used to screen stocks. Amounts are saved 
+    /// as text like "394.33B" or "147.94%" and are converted to a number.
+    pub const NUMERIC_FIELDS: &[(&str, FieldKind)] = &[
+        ("current_price", FieldKind::Number),
+        ("eps_ttm", FieldKind::Number),
+        ("pe_ratio", FieldKind::Number),
+        ("total_debt_equity", FieldKind::Number),
+        ("market_cap", FieldKind::Amount),
+        ("peg_ratio", FieldKind::Number),
+        ("price_to_book", FieldKind::Number),
+        ("revenue", FieldKind::Amount),
+        ("gross_profit", FieldKind::Amount),
+        ("total_cash", FieldKind::Amount),
+        ("total_debt", FieldKind::Amount),
+        ("return_on_equity", FieldKind::Amount),
+        ("return_on_assets", FieldKind::Amount),
+        ("bvps", FieldKind::Number),
+    ];
+
+    pub fn field_kind(name: &str) -> Option<FieldKind> {
+        NUMERIC_FIELDS.iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, kind)| *kind)
+    }
+
+    impl Stock {
+        pub fn field(&self, name: &str) -> Option<f64> {
+            match name {
+                "current_price" => Some(self.current_price),
+                "eps_ttm" => Some(self.eps_ttm),
+                "pe_ratio" => Some(self.pe_ratio),
+                "total_debt_equity" => Some(self.total_debt_equity),
+                "market_cap" => parse_amount(&self.market_cap),
+                "peg_ratio" => Some(self.peg_ratio),
+                "price_to_book" => Some(self.price_to_book),
+                "revenue" => parse_amount(&self.revenue),
+                "gross_profit" => parse_amount(&self.gross_profit),
+                "total_cash" => parse_amount(&self.total_cash),
+                "total_debt" => parse_amount(&self.total_debt),
+                "return_on_equity" => parse_amount(&self.return_on_equity),
+                "return_on_assets" => parse_amount(&self.return_on_assets),
+                "bvps" => Some(self.bvps),
+                _ => None
+            }
+        }
+    }
+
+    /// Text fields of a stock, which can be shown and sorted by next to the numeric fields.
+    pub const TEXT_FIELDS: &[&str] = &["name", "change_since", "sector", "industry", "country", "currency"];
+
+    impl Stock {
+        /// Value of a field as it's saved, e.g. "394.33B" for the revenue.
+        pub fn display(&self, name: &str) -> Option<String> {
+            match name {
+                "name" => Some(self.name.to_uppercase()),
+                "change_since" => Some(self.change_since.clone()),
+                "market_cap" => Some(self.market_cap.clone()),
+                "revenue" => Some(self.revenue.clone()),
+                "gross_profit" => Some(self.gross_profit.clone()),
+                "total_cash" => Some(self.total_cash.clone()),
+                "total_debt" => Some(self.total_debt.clone()),
+                "return_on_equity" => Some(self.return_on_equity.clone()),
+                "return_on_assets" => Some(self.return_on_assets.clone()),
+                "sector" => Some(self.sector.clone()),
+                "industry" => Some(self.industry.clone()),
+                "country" => Some(self.country.clone()),
+                "currency" => Some(self.currency.clone()),
+                _ => self.field(name).map(|value| value.to_string())
             }
         }
     }
 
-    pub async fn list(client: &mut Client) -> Result<(), Error> {
-        for row in client.query("SELECT * FROM stocks", &[]).await? {
-            let stock_name: String = row.get(1);
-            println!("{:?}", stock_name);
+    /// Amounts like "394.33B", "2.5k" or "147.94%", matched regardless of case. The same pattern
+    /// is used in Rust and in Postgres, so a screen finds the same stocks in both modes.
+    const AMOUNT_PATTERN: &str = "^[-+]?[0-9]+(\\.[0-9]+)?[KMBT%]?$";
+
+    /// The amount pattern, compiled on first use since amounts are parsed for every row of a screen.
+    static AMOUNT_REGEX: OnceLock<Regex> = OnceLock::new();
+
+    /// Converts amounts like "394.33B", "2.5k" or "147.94%" to a number.
+    pub fn parse_amount(value: &str) -> Option<f64> {
+        let value = value.trim();
+        let amount = AMOUNT_REGEX.get_or_init(|| Regex::new(&format!("(?i){}", AMOUNT_PATTERN)).unwrap());
+        if !amount.is_match(value) {
+            return None
         }
 
-        Ok(())
+        let (number, factor) = match value.chars().last()?.to_ascii_uppercase() {
+            'K' => (&value[..value.len() - 1], 1e3),
+            'M' => (&value[..value.len() - 1], 1e6),
+            'B' => (&value[..value.len() - 1], 1e9),
+            'T' => (&value[..value.len() - 1], 1e12),
+            '%' => (&value[..value.len() - 1], 1.0),
+            _ => (value, 1.0)
+        };
+
+        number.parse::<f64>().ok().map(|number| number * factor)
+    }
+
+    /// Formats a number the way amounts are saved, e.g. 394330000000 as "394.33B".
+    pub fn format_amount(value: f64) -> String {
+        let (number, suffix) = match value.abs() {
+            v if v >= 1e12 => (value / 1e12, "T"),
+            v if v >= 1e9 => (value / 1e9, "B"),
+            v if v >= 1e6 => (value / 1e6, "M"),
+            v if v >= 1e3 => (value / 1e3, "k"),
+            _ => (value, "")
+        };
+
+        format!("{:.2}{}", number, suffix)
+    }
+
+    /// SQL expression of a numeric field, doing the same conversion as `parse_amount`. 
+    /// Only fields of `NUMERIC_FIELDS` are accepted, so the name is safe to put into a query.
+    pub fn sql_column(name: &str) -> Option<String> {
+        match field_kind(name)? {
+            FieldKind::Number => Some(name.to_string()),
+            FieldKind::Amount => Some(format!(
+                "(CASE WHEN TRIM({0}) ~* '{1}' \
+                THEN REGEXP_REPLACE(TRIM({0}), '[KMBT%]', '', 'i')::float8 * \
+                CASE UPPER(RIGHT(TRIM({0}), 1)) WHEN 'K' THEN 1e3 WHEN 'M' THEN 1e6 \
+                WHEN 'B' THEN 1e9 WHEN 'T' THEN 1e12 ELSE 1 END END)", 
+                name, AMOUNT_PATTERN
+            ))
+        }
+    }
+
+    fn stock_from_row(row: &Row) -> Stock {
+        let classification = |i: usize| row.get::<_, Option<String>>(i).unwrap_or_else(|| "Unknown".to_string());
+
+        Stock {
+            name: row.get(1),
+            current_price: row.get(2),
+            eps_ttm: row.get(3),
+            pe_ratio: row.get(4),
+            total_debt_equity: row.get(5),
+            change_since: row.get(6),
+            market_cap: row.get(7),
+            peg_ratio: row.get(8),
+            price_to_book: row.get(9),
+            revenue: row.get(10),
+            gross_profit: row.get(11),
+            total_cash: row.get(12),
+            total_debt: row.get(13),
+            return_on_equity: row.get(14),
+            return_on_assets: row.get(15),
+            bvps: row.get(16),
+            sector: classification(17),
+            industry: classification(18),
+            country: classification(19),
+            currency: classification(20),
+        }
     }
 
-    pub async fn insert(client: &mut Client, name: String) -> Result<u64, Error> {
+    pub async fn list(client: &mut Client, list: Option<String>) -> Result<Vec<Stock>, Error> {
+        let rows = match list {
+            Some(list) => client.query(
+                "SELECT stocks.* FROM stocks 
+                JOIN watchlist_stocks ON LOWER(stocks.name) = watchlist_stocks.name 
+                WHERE watchlist_stocks.watchlist = $1", 
+                &[&list]
+            ).await?,
+            None => client.query("SELECT * FROM stocks", &[]).await?
+        };
+
+        Ok(rows.iter().map(stock_from_row).collect())
+    }
+
+    pub async fn insert(client: &mut Client, name: String) -> Result<u64, StockError> {
         let statement = client.prepare_typed(
             "INSERT INTO stocks 
             (NAME, CURRENT_PRICE, EPS_TTM, PE_RATIO, 
             TOTAL_DEBT_EQUITY, CHANGE_SINCE, MARKET_CAP,
             PEG_RATIO, PRICE_TO_BOOK, REVENUE, GROSS_PROFIT,
             TOTAL_CASH, TOTAL_DEBT, RETURN_ON_EQUITY,
-            RETURN_ON_ASSETS, BVPS) 
+            RETURN_ON_ASSETS, BVPS, SECTOR, INDUSTRY,
+            COUNTRY, CURRENCY) 
             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, 
-            $9, $10, $11, $12, $13, $14, $15, $16)",
+            $9, $10, $11, $12, $13, $14, $15, $16,
+            $17, $18, $19, $20)",
             &[Type::VARCHAR, Type::FLOAT8, Type::FLOAT8, 
                 Type::FLOAT8, Type::FLOAT8, Type::VARCHAR,
                 Type::VARCHAR, Type::FLOAT8, Type::FLOAT8,
                 Type::VARCHAR, Type::VARCHAR, Type::VARCHAR,
                 Type::VARCHAR, Type::VARCHAR, Type::VARCHAR,
-                Type::FLOAT8],
+                Type::FLOAT8, Type::VARCHAR, Type::VARCHAR,
+                Type::VARCHAR, Type::VARCHAR],
         ).await?;
 
         let url = stock_scraper::Url { symbol: name.clone() };
         
-        let uri = url.scrapped_home().await;
-        let uri_statistic = url.scrapped_statistics().await;
+        let uri = url.scrapped_home().await?;
+        let uri_statistic = url.scrapped_statistics().await?;
+        let uri_profile = url.scrapped_profile().await?;
 
         let stock_data = stock_scraper::StockData { symbol: name.clone(), url: uri };
         let stock_data_statistic = stock_scraper::StockData { symbol: name.clone(), url: uri_statistic };
+        let stock_data_profile = stock_scraper::StockData { symbol: name.clone(), url: uri_profile };
 
-        client.execute(&statement, 
+        Ok(client.execute(&statement, 
             &[
                 &name, 
-                &stock_data.current_price(), 
+                &stock_data.current_price()?, 
                 &stock_data.trailing_eps(), 
                 &stock_data.pe_ratio(),
                 &stock_data_statistic.debt_equity_ratio(), 
@@ -88,8 +269,12 @@ pub mod queries {
                 &stock_data_statistic.return_on_equity(),
                 &stock_data_statistic.return_on_assets(),
                 &stock_data_statistic.bvps(),
+                &stock_data_profile.sector(),
+                &stock_data_profile.industry(),
+                &stock_data_profile.country(),
+                &stock_data.currency(),
             ]
-        ).await
+        ).await?)
     }
 
     pub async fn drop(client: &mut Client, name: String) -> Result<u64, Error> {
@@ -101,7 +286,7 @@ pub mod queries {
         client.execute(&statement, &[&name]).await
     }
 
-    pub async fn update(client: &mut Client, name: String) -> Result<u64, Error> {
+    pub async fn update(client: &mut Client, name: String) -> Result<u64, StockError> {
         let statement = client.prepare_typed(
             "UPDATE stocks SET 
             current_price = $2, 
@@ -118,27 +303,34 @@ pub mod queries {
             total_debt = $13,
             return_on_equity = $14,
             return_on_assets = $15,
-            bvps = $16 WHERE name = $1",
+            bvps = $16,
+            sector = $17,
+            industry = $18,
+            country = $19,
+            currency = $20 WHERE name = $1",
             &[Type::VARCHAR, Type::FLOAT8, Type::FLOAT8, 
                 Type::FLOAT8, Type::FLOAT8, Type::VARCHAR,
                 Type::VARCHAR, Type::FLOAT8, Type::FLOAT8,
                 Type::VARCHAR, Type::VARCHAR, Type::VARCHAR,
                 Type::VARCHAR, Type::VARCHAR, Type::VARCHAR,
-                Type::FLOAT8],
+                Type::FLOAT8, Type::VARCHAR, Type::VARCHAR,
+                Type::VARCHAR, Type::VARCHAR],
         ).await?;
         
         let url = stock_scraper::Url { symbol: name.clone() };
         
-        let uri = url.scrapped_home().await;
-        let uri_statistic = url.scrapped_statistics().await;
+        let uri = url.scrapped_home().await?;
+        let uri_statistic = url.scrapped_statistics().await?;
+        let uri_profile = url.scrapped_profile().await?;
         
         let stock_data = stock_scraper::StockData { symbol: name.clone(), url: uri };
         let stock_data_statistic = stock_scraper::StockData { symbol: name.clone(), url: uri_statistic };
+        let stock_data_profile = stock_scraper::StockData { symbol: name.clone(), url: uri_profile };
         
-        client.execute(&statement, 
+        Ok(client.execute(&statement, 
             &[
                 &name, 
-                &stock_data.current_price(), 
+                &stock_data.current_price()?, 
                 &stock_data.trailing_eps(), 
                 &stock_data.pe_ratio(), 
                 &stock_data_statistic.debt_equity_ratio(), 
@@ -153,12 +345,28 @@ pub mod queries {
                 &stock_data_statistic.return_on_equity(),
                 &stock_data_statistic.return_on_assets(),
                 &stock_data_statistic.bvps(),
+                &stock_data_profile.sector(),
+                &stock_data_profile.industry(),
+                &stock_data_profile.country(),
+                &stock_data.currency(),
             ]
-        ).await
+        ).await?)
     }
 
-    pub async fn update_all(client: &mut Client) {
-        for row in client.query("SELECT name FROM stocks", &[]).await.unwrap() {
+    /// Updates the stocks one by one and returns the symbols which couldn't be updated.
+    pub async fn update_all(client: &mut Client, list: Option<String>) -> Result<Vec<String>, Error> {
+        let rows = match list {
+            Some(list) => client.query(
+                "SELECT stocks.name FROM stocks 
+                JOIN watchlist_stocks ON LOWER(stocks.name) = watchlist_stocks.name 
+                WHERE watchlist_stocks.watchlist = $1", 
+                &[&list]
+            ).await?,
+            None => client.query("SELECT name FROM stocks", &[]).await?
+        };
+        let mut failed = Vec::new();
+
+        for row in rows {
             let stock = Stock {
                 name: row.get(0),
                 current_price: 0.0,
@@ -176,6 +384,10 @@ pub mod queries {
                 return_on_equity: "".to_string(),
                 return_on_assets: "".to_string(),
                 bvps: 0.0,
+                sector: "".to_string(),
+                industry: "".to_string(),
+                country: "".to_string(),
+                currency: "".to_string(),
             };
 
             match update(client, stock.clone().name).await {
@@ -183,61 +395,70 @@ pub mod queries {
                     if result == 1 {
                         println!("Stock updated: {}", stock.name)
                     } else if result == 0 {
-                        println!("Stock could not be updated: {}", stock.name)
+                        println!("Stock could not be updated: {}", stock.name);
+                        failed.push(stock.name)
                     }
                 },
-                Err(e) => println!("Error: {}.", e),
+                Err(e) => {
+                    println!("Error: {}.", e);
+                    failed.push(stock.name)
+                },
             }
         };
-        println!("Updating finished!")
+        println!("Updating finished!");
+
+        Ok(failed)
     }
 
     pub async fn search(client: &mut Client, name: String, print: bool) -> Result<Stock, Error> {
         let mut stock = Stock::default();
         for row in client.query("SELECT * FROM stocks WHERE name = $1", &[&name]).await? {
-            stock.name = row.get(1);
-            stock.current_price = row.get(2);
-            stock.eps_ttm = row.get(3);
-            stock.pe_ratio = row.get(4);
-            stock.total_debt_equity = row.get(5);
-            stock.change_since = row.get(6);
-            stock.market_cap = row.get(7);
-            stock.peg_ratio = row.get(8);
-            stock.price_to_book = row.get(9);
-            stock.revenue = row.get(10);
-            stock.gross_profit = row.get(11);
-            stock.total_cash = row.get(12);
-            stock.total_debt = row.get(13);
-            stock.return_on_equity = row.get(14);
-            stock.return_on_assets = row.get(15);
-            stock.bvps = row.get(16);
+            stock = stock_from_row(&row);
 
             if print {
-                println!("Stock: {}", stock.name.to_uppercase());
-                println!("  - Current Price: {} {}", stock.current_price, stock.change_since);
-                println!("  - Market Cap: {}", stock.market_cap);
-                println!("  - EPS (ttm): {}", stock.eps_ttm);
-                println!("  - P/E: {}", stock.pe_ratio);
-                println!("  - PEG ratio: {}", stock.peg_ratio);
-                println!("  - Price/Book (mrq): {}", stock.price_to_book);
-                println!("  - Book Value per Share (mrq): {}", stock.bvps);
-                println!("  - Revenue (ttm): {}", stock.revenue);
-                println!("  - Gross Profit (ttm): {}", stock.gross_profit);
-                println!("  - Total Cash (mrq): {}", stock.total_cash);
-                println!("  - Total Debt (mrq): {}", stock.total_debt);
-                println!("  - Total Debt/Equity: {}", stock.total_debt_equity);
-                println!("  - Return on Equity (ttm): {}", stock.return_on_equity);
-                println!("  - Return on Assets (ttm): {}", stock.return_on_assets);
+                print_stock(&stock, current_locale());
             }
         }
 
         if client.query("SELECT * FROM stocks WHERE name = $1", &[&name]).await.unwrap().is_empty() {
-            println!("Stock was not found")
+            println!("{}", current_locale().label("Stock was not found."))
         }
 
         Ok(stock)
     }
 
+    /// Prints the key numbers of a stock with the labels and number format of the locale.
+    pub fn print_stock(stock: &Stock, locale: Locale) {
+        let number = |value: f64| locale.value(&value.to_string());
+        let (change, since) = stock.change_since.split_once(' ').unwrap_or((&stock.change_since, ""));
+
+        let values = [
+            ("Current Price", format!("{} {} {}", number(stock.current_price), locale.value(change), since).trim_end().to_string()),
+            ("Market Cap", locale.value(&stock.market_cap)),
+            ("EPS (ttm)", number(stock.eps_ttm)),
+            ("P/E", number(stock.pe_ratio)),
+            ("PEG ratio", number(stock.peg_ratio)),
+            ("Price/Book (mrq)", number(stock.price_to_book)),
+            ("Book Value per Share (mrq)", number(stock.bvps)),
+            ("Revenue (ttm)", locale.value(&stock.revenue)),
+            ("Gross Profit (ttm)", locale.value(&stock.gross_profit)),
+            ("Total Cash (mrq)", locale.value(&stock.total_cash)),
+            ("Total Debt (mrq)", locale.value(&stock.total_debt)),
+            ("Total Debt/Equity", number(stock.total_debt_equity)),
+            ("Return on Equity (ttm)", locale.value(&stock.return_on_equity)),
+            ("Return on Assets (ttm)", locale.value(&stock.return_on_assets)),
+            ("Sector", stock.sector.clone()),
+            ("Industry", stock.industry.clone()),
+            ("Country", stock.country.clone()),
+            ("Currency", stock.currency.clone()),
+        ];
+
+        println!("{}: {}", locale.label("Stock"), stock.name.to_uppercase());
+        for (label, value) in values {
+            println!("  - {}: {}", locale.label(label), value);
+        }
+    }
+
     pub async fn stock_exists(client: &mut Client, name: String) -> bool {
         let stock_found = client.query(
             "SELECT * FROM stocks WHERE name = $1", &[&name]
@@ -245,4 +466,50 @@ pub mod queries {
 
         stock_found > 0
     }
-}
+
+    pub async fn current_prices(client: &mut Client) -> Result<HashMap<String, f64>, Error> {
+        let mut prices = HashMap::new();
+
+        for row in client.query("SELECT name, current_price FROM stocks", &[]).await? {
+            let name: String = row.get(0);
+            prices.insert(name.to_lowercase(), row.get(1));
+        }
+
+        Ok(prices)
+    }
+
+    /// Sector, industry, country and currency per stock. Stocks which weren't updated 
+    /// since these were added are reported as Unknown.
+    pub async fn classifications(client: &mut Client) -> Result<HashMap<String, Classification>, Error> {
+        let mut classifications = HashMap::new();
+
+        for row in client.query("SELECT name, sector, industry, country, currency FROM stocks", &[]).await? {
+            let name: String = row.get(0);
+            let value = |i: usize| row.get::<_, Option<String>>(i).unwrap_or_else(|| "Unknown".to_string());
+
+            classifications.insert(name.to_lowercase(), Classification {
+                sector: value(1),
+                industry: value(2),
+                country: value(3),
+                currency: value(4),
+            });
+        }
+
+        Ok(classifications)
+    }
+
+    /// Stocks matching a screen. The expression is translated to a WHERE clause 
+    /// with its numbers passed as parameters.
+    pub async fn screen(client: &mut Client, condition: &str, params: &[f64]) -> Result<Vec<Stock>, Error> {
+        let params: Vec<&(dyn ToSql + Sync)> = params.iter()
+            .map(|param| param as &(dyn ToSql + Sync))
+            .collect();
+
+        let rows = client.query(
+            format!("SELECT * FROM stocks WHERE {} ORDER BY name", condition).as_str(), 
+            &params
+        ).await?;
+
+        Ok(rows.iter().map(stock_from_row).collect())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,74 @@
+pub mod statements {
+    use tokio_postgres::{Client, Error, types::Type};
+
+    use crate::statements::statements::statements::{Frequency, Kind, StatementRow};
+
+    /// Replaces the stored statement of the symbol with the given rows.
+    pub async fn save(
+        client: &mut Client, symbol: &str, kind: Kind, frequency: Frequency, rows: &[StatementRow]
+    ) -> Result<u64, Error> {
+        client.execute(
+            "DELETE FROM statements WHERE symbol = $1 AND statement = $2 AND frequency = $3",
+            &[&symbol, &kind.as_str(), &frequency.as_str()]
+        ).await?;
+
+        let statement = client.prepare_typed(
+            "INSERT INTO statements 
+            (SYMBOL, STATEMENT, FREQUENCY, PERIOD, ITEM, VALUE) 
+            VALUES ($1, $2, $3, $4, $5, $6)",
+            &[Type::VARCHAR, Type::VARCHAR, Type::VARCHAR, 
+                Type::VARCHAR, Type::VARCHAR, Type::FLOAT8],
+        ).await?;
+
+        let mut inserted = 0;
+
+        for row in rows {
+            inserted += client.execute(&statement, 
+                &[
+                    &row.symbol,
+                    &row.kind.as_str(),
+                    &row.frequency.as_str(),
+                    &row.period,
+                    &row.item,
+                    &row.value,
+                ]
+            ).await?;
+        }
+
+        Ok(inserted)
+    }
+
+    pub async fn list(
+        client: &mut Client, symbol: &str, kind: Kind, frequency: Frequency
+    ) -> Result<Vec<StatementRow>, Error> {
+        let mut rows = Vec::new();
+
+        for row in client.query(
+            "SELECT symbol, period, item, value FROM statements 
+            WHERE symbol = $1 AND statement = $2 AND frequency = $3 ORDER BY period", 
+            &[&symbol, &kind.as_str(), &frequency.as_str()]
+        ).await? {
+            rows.push(StatementRow {
+                symbol: row.get(0),
+                kind,
+                frequency,
+                period: row.get(1),
+                item: row.get(2),
+                value: row.get(3),
+            });
+        }
+
+        Ok(rows)
+    }
+
+    /// All stored statements of the symbol with the given frequency.
+    pub async fn for_symbol(client: &mut Client, symbol: &str, frequency: Frequency) -> Result<Vec<StatementRow>, Error> {
+        let mut rows = Vec::new();
+
+        for kind in [Kind::Income, Kind::Balance, Kind::CashFlow] {
+            rows.extend(list(client, symbol, kind, frequency).await?);
+        }
+
+        Ok(rows)
+    }
+}
\ No newline at end of file
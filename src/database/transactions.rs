@@ -0,0 +1,54 @@
+pub mod transactions {
+    use chrono::NaiveDate;
+    use tokio_postgres::{Client, Error, types::Type};
+
+    use crate::portfolio::transactions::transactions::{Kind, Transaction, DATE_FORMAT};
+
+    pub async fn insert(client: &mut Client, transaction: &Transaction) -> Result<u64, Error> {
+        let statement = client.prepare_typed(
+            "INSERT INTO transactions 
+            (SYMBOL, KIND, QUANTITY, PRICE, FEE, DATE) 
+            VALUES ($1, $2, $3, $4, $5, $6)",
+            &[Type::VARCHAR, Type::VARCHAR, Type::FLOAT8, 
+                Type::FLOAT8, Type::FLOAT8, Type::VARCHAR],
+        ).await?;
+
+        client.execute(&statement, 
+            &[
+                &transaction.symbol,
+                &transaction.kind.as_str(),
+                &transaction.quantity,
+                &transaction.price,
+                &transaction.fee,
+                &transaction.date.format(DATE_FORMAT).to_string(),
+            ]
+        ).await
+    }
+
+    pub async fn list(client: &mut Client) -> Result<Vec<Transaction>, Error> {
+        let mut transactions = Vec::new();
+
+        for row in client.query(
+            "SELECT symbol, kind, quantity, price, fee, date FROM transactions ORDER BY date, id", &[]
+        ).await? {
+            let kind: String = row.get(1);
+            let date: String = row.get(5);
+
+            let (kind, date) = match (Kind::parse(&kind), NaiveDate::parse_from_str(&date, DATE_FORMAT)) {
+                (Some(kind), Ok(date)) => (kind, date),
+                _ => continue
+            };
+
+            transactions.push(Transaction {
+                symbol: row.get(0),
+                kind,
+                quantity: row.get(2),
+                price: row.get(3),
+                fee: row.get(4),
+                date,
+            });
+        }
+
+        Ok(transactions)
+    }
+}
@@ -0,0 +1,74 @@
+pub mod watchlists {
+    use tokio_postgres::{Client, Error, types::Type};
+
+    pub async fn create(client: &mut Client, name: String) -> Result<u64, Error> {
+        let statement = client.prepare_typed(
+            "INSERT INTO watchlists (NAME) VALUES ($1)",
+            &[Type::VARCHAR],
+        ).await?;
+
+        client.execute(&statement, &[&name]).await
+    }
+
+    pub async fn delete(client: &mut Client, name: String) -> Result<u64, Error> {
+        client.execute("DELETE FROM watchlist_stocks WHERE watchlist = $1", &[&name]).await?;
+        client.execute("DELETE FROM watchlists WHERE name = $1", &[&name]).await
+    }
+
+    pub async fn exists(client: &mut Client, name: String) -> Result<bool, Error> {
+        let rows = client.query("SELECT name FROM watchlists WHERE name = $1", &[&name]).await?;
+
+        Ok(!rows.is_empty())
+    }
+
+    pub async fn add_stock(client: &mut Client, name: String, stock_name: String) -> Result<u64, Error> {
+        let already_added = client.query(
+            "SELECT name FROM watchlist_stocks WHERE watchlist = $1 AND name = $2", 
+            &[&name, &stock_name]
+        ).await?;
+
+        if !already_added.is_empty() {
+            return Ok(0)
+        }
+
+        let statement = client.prepare_typed(
+            "INSERT INTO watchlist_stocks (WATCHLIST, NAME) VALUES ($1, $2)",
+            &[Type::VARCHAR, Type::VARCHAR],
+        ).await?;
+
+        client.execute(&statement, &[&name, &stock_name]).await
+    }
+
+    pub async fn remove_stock(client: &mut Client, name: String, stock_name: String) -> Result<u64, Error> {
+        client.execute(
+            "DELETE FROM watchlist_stocks WHERE watchlist = $1 AND name = $2", 
+            &[&name, &stock_name]
+        ).await
+    }
+
+    /// Removes a dropped stock from all lists.
+    pub async fn remove_stock_from_all(client: &mut Client, stock_name: String) -> Result<u64, Error> {
+        client.execute("DELETE FROM watchlist_stocks WHERE name = $1", &[&stock_name]).await
+    }
+
+    pub async fn stocks(client: &mut Client, name: String) -> Result<Vec<String>, Error> {
+        let rows = client.query(
+            "SELECT name FROM watchlist_stocks WHERE watchlist = $1 ORDER BY name", &[&name]
+        ).await?;
+
+        Ok(rows.iter().map(|row| row.get(0)).collect())
+    }
+
+    pub async fn all(client: &mut Client) -> Result<Vec<(String, Vec<String>)>, Error> {
+        let mut lists = Vec::new();
+
+        for row in client.query("SELECT name FROM watchlists ORDER BY name", &[]).await? {
+            let name: String = row.get(0);
+            let stocks = stocks(client, name.clone()).await?;
+
+            lists.push((name, stocks));
+        }
+
+        Ok(lists)
+    }
+}
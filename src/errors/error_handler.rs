@@ -28,6 +28,57 @@ pub mod error_handler {
         FileNotReadable(#[from] serde_yaml::Error),
     }
 
+    #[derive(Debug, Error)]
+    pub enum BenchmarkError {
+        #[error("No benchmark is set. Run 'set-benchmark' and pass a symbol such as ^GSPC.")]
+        FileMissing(#[from] std::io::Error),
+
+        #[error("Error occurred when reading your benchmark. Please run 'set-benchmark' again.")]
+        FileNotReadable(#[from] serde_yaml::Error),
+    }
+
+    #[derive(Debug, Error)]
+    pub enum LocaleError {
+        #[error("No locale is set. Run 'set-locale' and pass en or de.")]
+        FileMissing(#[from] std::io::Error),
+
+        #[error("config/locale.yml is not readable: {0}")]
+        FileNotReadable(#[from] serde_yaml::Error),
+
+        #[error("Locale {locale:?} is not supported. Use en or de.")]
+        UnknownLocale { locale: String },
+    }
+
+    #[derive(Debug, Error)]
+    pub enum TargetError {
+        #[error("Targets could not be saved: {0}")]
+        FileNotWritable(#[from] std::io::Error),
+
+        #[error("config/targets.yml is not readable: {0}")]
+        FileNotReadable(#[from] serde_yaml::Error),
+    }
+
+    #[derive(Debug, Error)]
+    pub enum GlossaryError {
+        #[error("config/glossary.yml could not be read: {0}")]
+        FileNotReadable(#[from] std::io::Error),
+
+        #[error("Terms of config/glossary.yml are not valid, they're left out: {0}")]
+        InvalidTerms(#[from] serde_yaml::Error),
+    }
+
+    #[derive(Debug, Error)]
+    pub enum ScreenError {
+        #[error("Unknown field '{field}'. Available fields: {available}")]
+        UnknownField { field: String, available: String },
+
+        #[error("Unexpected '{token}' in screen. Use e.g. \"pe_ratio < 15 and peg_ratio < 1\".")]
+        UnexpectedToken { token: String },
+
+        #[error("Screen ended unexpectedly. Use e.g. \"pe_ratio < 15 and peg_ratio < 1\".")]
+        UnexpectedEnd,
+    }
+
     #[derive(Debug, Error)]
     pub enum YahooError {
         #[error("Could not get value for {value:?}")]
@@ -35,9 +86,54 @@ pub mod error_handler {
             value: String
         },
 
+        #[error("Could not reach Yahoo Finance for {value:?}")]
+        RequestFailed {
+            value: String,
+        },
+
         #[error("Could not parse data for {value:?}")]
         RegexError {
             value: String,
         },
+
+        #[error("Could not get price history for {value:?}")]
+        HistoryUnavailable {
+            value: String,
+        },
+
+        #[error("Could not get financial statements for {value:?}")]
+        StatementUnavailable {
+            value: String,
+        },
+    }
+
+    /// A stock which couldn't be scraped or saved.
+    #[derive(Debug, Error)]
+    pub enum StockError {
+        #[error("{0}")]
+        Scrape(#[from] YahooError),
+
+        #[error("{0}")]
+        Database(#[from] tokio_postgres::Error),
+    }
+
+    #[derive(Debug, Error)]
+    pub enum ImportError {
+        #[error("File could not be read: {0}")]
+        FileMissing(#[from] std::io::Error),
+
+        #[error("config/brokers.yml is not readable: {0}")]
+        ConfigNotReadable(#[from] serde_yaml::Error),
+
+        #[error("Format {format:?} is not defined in config/brokers.yml. Available formats: {available}")]
+        UnknownFormat {
+            format: String,
+            available: String,
+        },
+
+        #[error("Column {column:?} was not found in the header of the CSV file.")]
+        MissingColumn {
+            column: String,
+        },
     }
 }
\ No newline at end of file
@@ -1,2 +1,6 @@
 pub mod queries;
-pub mod cmd;
\ No newline at end of file
+pub mod cmd;
+pub mod transactions;
+pub mod watchlists;
+pub mod statements;
+pub mod alerts;
\ No newline at end of file
@@ -0,0 +1,61 @@
+pub mod alerts {
+    use std::{fs::File, io::Write};
+
+    use crate::analytics::alerts::alerts::Alert;
+    use crate::file::queries::queries::file_to_string;
+
+    const ALERTS_FILE: &str = "config/alerts.txt";
+
+    /// Every alert is saved as one row: `id,symbol,condition;`
+    pub fn list() -> Vec<Alert> {
+        let file = match File::open(ALERTS_FILE) {
+            Ok(file) => file,
+            Err(_) => return vec![]
+        };
+
+        file_to_string(&file)
+            .split(';')
+            .filter_map(|row| {
+                let mut fields = row.trim().splitn(3, ',');
+
+                Some(Alert {
+                    id: fields.next()?.parse::<i32>().ok()?,
+                    symbol: fields.next()?.to_string(),
+                    condition: fields.next()?.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    /// Saves the alert and returns its id.
+    pub fn insert(symbol: &str, condition: &str) -> Result<i32, std::io::Error> {
+        let mut alerts = list();
+        let id = alerts.iter().map(|alert| alert.id).max().unwrap_or(0) + 1;
+
+        alerts.push(Alert { id, symbol: symbol.to_string(), condition: condition.to_string() });
+        save(&alerts)?;
+
+        Ok(id)
+    }
+
+    pub fn delete(id: i32) -> Result<bool, std::io::Error> {
+        let mut alerts = list();
+        let count = alerts.len();
+
+        alerts.retain(|alert| alert.id != id);
+        save(&alerts)?;
+
+        Ok(alerts.len() < count)
+    }
+
+    fn save(alerts: &[Alert]) -> Result<(), std::io::Error> {
+        let mut file = File::create(ALERTS_FILE)?;
+
+        for alert in alerts {
+            let row = format!("{},{},{};\n", alert.id, alert.symbol, alert.condition);
+            file.write_all(row.as_bytes())?;
+        }
+
+        Ok(())
+    }
+}
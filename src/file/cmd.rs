@@ -1,14 +1,45 @@
 pub mod cmd {
-    use std::fs::{OpenOptions, File};
+    use std::{collections::HashMap, fs::{OpenOptions, File}};
 
+    use chrono::{Datelike, Local};
     use tokio_postgres::Error;
 
 
-    use crate::fundamentals::explanations::print_expl;
-    use crate::{Opt, Command, init_mode};
+    use crate::fundamentals::glossary::glossary::{print_expl, print_search};
+    use crate::fundamentals::evaluation::evaluation::print_evaluation;
+    use crate::analytics::statistics::statistics::print_stats;
+    use crate::analytics::compare::compare::print_comparison;
+    use crate::analytics::peers::peers::print_peers;
+    use crate::analytics::alerts::alerts::{TRIGGERED_EXIT_CODE, symbols, print_alerts, print_triggered, skip_alerts};
+    use crate::analytics::watch::watch::watch;
+    use crate::output::listing::listing::print_stocks;
+    use crate::output::locale::locale::{Locale, current_locale, set_locale};
+    use crate::errors::error_handler::error_handler::LocaleError;
+    use crate::statements::statements::statements::{Frequency, Kind as StatementKind, print_statement};
+    use crate::statements::ratios::ratios::print_ratios;
+    use crate::statements::scores::scores::print_scores;
+    use crate::scraper::timeseries::timeseries::fetch_statement;
+    use crate::valuation::dcf::dcf::{Assumptions, print_dcf};
+    use crate::valuation::graham::graham::print_value;
+    use crate::analytics::screener::screener::{Expression, print_screen};
+    use crate::database::queries::queries::{Stock, parse_amount};
+    use crate::analytics::benchmark::benchmark::{read_benchmark, set_benchmark, print_benchmark};
+    use crate::{Opt, Command, AlertCommand, ListCommand, PortfolioCommand, TargetCommand, init_mode};
     use crate::file::queries::queries as file_query;
+    use crate::file::transactions::transactions as transaction_query;
+    use crate::file::watchlists::watchlists as watchlist_query;
+    use crate::file::alerts::alerts as alert_query;
+    use crate::file::statements::statements as statement_query;
+    use crate::portfolio::transactions::transactions::{Kind, Transaction, validate};
+    use crate::portfolio::positions::positions::print_portfolio;
+    use crate::portfolio::gains::gains::{Method, print_gains};
+    use crate::portfolio::dividends::dividends::print_dividends;
+    use crate::portfolio::returns::returns::print_returns;
+    use crate::portfolio::allocation::allocation::{Dimension, print_allocation};
+    use crate::portfolio::rebalance::rebalance::{read_targets, set_target, print_targets, print_rebalance};
+    use crate::portfolio::import::import::{Status, read_format, read_transactions, unknown_symbols, reject_symbol, print_preview};
     use crate::scraper::financial_data::get_financial_data::{
-        self as stock_scraper, calc_historical_price, split_date, print_history_price
+        self as stock_scraper, calc_historical_price, split_date, parse_period, print_history_price
     };
 
     pub async fn historical_price(
@@ -21,61 +52,519 @@ pub mod cmd {
 
     pub async fn run(opt: Opt) -> Result<(), Error> {
         match opt.cmd {
-            Command::Add { stock_name } => {
-                let file = OpenOptions::new()
-                                .append(true)
-                                .open("config/stocks.txt")
-                                .unwrap();
+            Command::Add { stock_name, list } => {
+                if let Some(list) = &list {
+                    if watchlist_query::stocks(&list.to_lowercase()).is_none() {
+                        println!("List {} does not exist. Run 'list create {}' first.", list, list);
+                        return Ok(())
+                    }
+                }
 
-                let url = stock_scraper::Url { symbol: stock_name.clone() };
+                let symbols = file_query::symbols(File::open("config/stocks.txt").unwrap());
+                let stock_already_exists = symbols.contains(&stock_name.to_lowercase());
 
-                let selector = ::scraper::Selector::parse("section[id='lookup-page']");
+                let mut stock_available = stock_already_exists;
 
-                match selector {
-                    Ok(selector_parse) => {
-                        let uri = url.scrapped_home().await;
-                        let html = uri.select(&selector_parse).next();
-        
-                        if html == None {
-                            file_query::insert(&file, stock_name, false).await;
-                        } else {
-                            println!("Stock symbol is not valid. Make sure that it exists.");
+                if stock_already_exists {
+                    if list.is_none() {
+                        println!("Stock already exists")
+                    }
+                } else {
+                    stock_available = add_stock(stock_name.clone()).await;
+                }
+
+                if let (Some(list), true) = (list, stock_available) {
+                    match watchlist_query::add_stock(&list.to_lowercase(), stock_name.to_lowercase()) {
+                        Ok(true) => println!("Stock was added to list {}!", list),
+                        Ok(false) => println!("Stock is already in list {}.", list),
+                        Err(e) => println!("Error occurred when adding stock to list: {}", e)
+                    }
+                }
+            }
+            Command::List { action, list, columns, sort, desc, limit } => {
+                match action {
+                    Some(ListCommand::Create { name }) => {
+                        match watchlist_query::create(name.to_lowercase()) {
+                            Ok(true) => println!("List {} was created!", name),
+                            Ok(false) => println!("List {} already exists.", name),
+                            Err(e) => println!("Error occurred when creating list: {}", e)
+                        }
+                    },
+                    Some(ListCommand::Delete { name }) => {
+                        match watchlist_query::delete(&name.to_lowercase()) {
+                            Ok(true) => println!("List {} was deleted!", name),
+                            Ok(false) => println!("List {} was not found.", name),
+                            Err(e) => println!("Error occurred when deleting list: {}", e)
+                        }
+                    },
+                    Some(ListCommand::Show {}) => {
+                        for (name, stocks) in watchlist_query::all() {
+                            println!("{} ({}): {}", name, stocks.len(), stocks.join(", ").to_uppercase());
                         }
                     },
-                    Err(e) => println!("Error occurred when trying to parse page: {:?}", e)
+                    None => {
+                        let members = match &list {
+                            Some(list) => match watchlist_query::stocks(&list.to_lowercase()) {
+                                Some(stocks) => Some(stocks),
+                                None => {
+                                    println!("List {} was not found.", list);
+                                    return Ok(())
+                                }
+                            },
+                            None => None
+                        };
+
+                        let file = File::open("config/stocks.txt").unwrap();
+
+                        print_stocks(file_query::list(file, members), columns, sort, desc, limit)
+                    }
                 }
             }
-            Command::List {} => {
-                let file = File::open("config/stocks.txt").unwrap();
+            Command::Peers { stock_names } => {
+                let mut stocks: Vec<Stock> = Vec::new();
+
+                for stock_name in stock_names {
+                    let stock_name = stock_name.to_lowercase();
+
+                    if stocks.iter().any(|stock| stock.name == stock_name) {
+                        continue
+                    }
 
-                file_query::list(file);
+                    let symbols = file_query::symbols(File::open("config/stocks.txt").unwrap());
+                    if !symbols.contains(&stock_name) && !add_stock(stock_name.clone()).await {
+                        continue
+                    }
+
+                    if let Some(stock) = file_query::find(File::open("config/stocks.txt").unwrap(), &stock_name) {
+                        stocks.push(stock)
+                    }
+                }
+
+                print_peers(&stocks)
             }
-            Command::Info { explanation } => {
-                let expl = explanation.to_lowercase();
-    
-                print_expl(expl)
+            Command::Alert { action } => {
+                match action {
+                    AlertCommand::Add { stock_name, condition } => {
+                        let condition = condition.join(" ");
+                        if let Err(e) = Expression::parse(&condition) {
+                            println!("{}", e);
+                            return Ok(())
+                        }
+
+                        let stock_name = stock_name.to_lowercase();
+                        let symbols = file_query::symbols(File::open("config/stocks.txt").unwrap());
+                        if !symbols.contains(&stock_name) && !add_stock(stock_name.clone()).await {
+                            return Ok(())
+                        }
+
+                        match alert_query::insert(&stock_name, &condition) {
+                            Ok(id) => println!("Alert {} was added!", id),
+                            Err(e) => println!("Error occurred when trying to add alert: {}", e)
+                        }
+                    },
+                    AlertCommand::Remove { id } => {
+                        match alert_query::delete(id) {
+                            Ok(true) => println!("Alert {} was removed", id),
+                            Ok(false) => println!("Alert {} could not be found", id),
+                            Err(e) => println!("Error occurred when trying to remove alert: {}", e)
+                        }
+                    },
+                    AlertCommand::List {} => {
+                        print_alerts(&alert_query::list())
+                    },
+                    AlertCommand::Check {} => {
+                        let mut alerts = alert_query::list();
+                        if alerts.is_empty() {
+                            print_alerts(&alerts);
+                            return Ok(())
+                        }
+
+                        for symbol in symbols(&alerts) {
+                            let file = File::open("config/stocks.txt").unwrap();
+
+                            match file_query::update(file, symbol.clone()).await {
+                                Ok(true) => {},
+                                Ok(false) => skip_alerts(&mut alerts, &symbol),
+                                Err(e) => {
+                                    println!("Stock could not be updated: {}. {}", symbol.to_uppercase(), e);
+                                    skip_alerts(&mut alerts, &symbol)
+                                }
+                            }
+                        }
+                        println!();
+
+                        let stocks = file_query::stocks(File::open("config/stocks.txt").unwrap());
+
+                        if print_triggered(&alerts, &stocks) {
+                            std::process::exit(TRIGGERED_EXIT_CODE)
+                        }
+                    }
+                }
+            }
+            Command::Buy { stock_name, quantity, price, date, fee } => {
+                record_transaction(Kind::Buy, stock_name, quantity, price, date, fee)
+            }
+            Command::Sell { stock_name, quantity, price, date, fee } => {
+                record_transaction(Kind::Sell, stock_name, quantity, price, date, fee)
+            }
+            Command::Portfolio { view } => {
+                let transactions = transaction_query::list();
+
+                match view {
+                    Some(PortfolioCommand::Returns { since, to }) => {
+                        let (from, to) = match parse_period(since, to) {
+                            Ok(period) => period,
+                            Err(e) => {
+                                println!("{}", e);
+                                return Ok(())
+                            }
+                        };
+
+                        print_returns(&transactions, from, to).await
+                    },
+                    Some(PortfolioCommand::Allocation { by, warn_above }) => {
+                        let dimension = match Dimension::parse(&by) {
+                            Some(dimension) => dimension,
+                            None => {
+                                println!("Allocation by {} is not supported. Use sector, industry, country or currency.", by);
+                                return Ok(())
+                            }
+                        };
+
+                        let prices = file_query::current_prices(File::open("config/stocks.txt").unwrap());
+                        let classifications = file_query::classifications(File::open("config/stocks.txt").unwrap());
+
+                        print_allocation(&transactions, &prices, &classifications, dimension, warn_above)
+                    },
+                    None => {
+                        let prices = file_query::current_prices(File::open("config/stocks.txt").unwrap());
+
+                        print_portfolio(&transactions, &prices)
+                    }
+                }
+            }
+            Command::ImportTransactions { csv, format, dry_run } => {
+                let broker_format = match read_format(&format) {
+                    Ok(broker_format) => broker_format,
+                    Err(e) => {
+                        println!("{}", e);
+                        return Ok(())
+                    }
+                };
+
+                let transactions = transaction_query::list();
+
+                let mut rows = match read_transactions(&csv, &broker_format, &transactions) {
+                    Ok(rows) => rows,
+                    Err(e) => {
+                        println!("{}", e);
+                        return Ok(())
+                    }
+                };
+
+                let known = file_query::symbols(File::open("config/stocks.txt").unwrap());
+                let unknown = unknown_symbols(&rows, &known);
+
+                print_preview(&rows, &unknown);
+
+                if dry_run {
+                    println!("Dry run, nothing was imported.");
+                    return Ok(())
+                }
+
+                for symbol in unknown {
+                    if !add_stock(symbol.clone()).await {
+                        reject_symbol(&mut rows, &symbol);
+                        println!("Transactions of {} are skipped.", symbol.to_uppercase());
+                    }
+                }
+
+                let mut imported = 0;
+                for row in rows.iter().filter(|row| row.status == Status::New) {
+                    if let Some(transaction) = &row.transaction {
+                        match transaction_query::insert(transaction) {
+                            Ok(_) => imported += 1,
+                            Err(e) => println!("Error occurred when recording transaction: {}", e)
+                        }
+                    }
+                }
+
+                println!("{} transactions were imported!", imported);
+            }
+            Command::Dividends { forecast } => {
+                let transactions = transaction_query::list();
+
+                print_dividends(&transactions, forecast).await
+            }
+            Command::Gains { year, method } => {
+                let method = match Method::parse(&method) {
+                    Some(method) => method,
+                    None => {
+                        println!("Method {} is not supported. Use fifo, lifo or average.", method);
+                        return Ok(())
+                    }
+                };
+
+                let transactions = transaction_query::list();
+
+                print_gains(&transactions, year.unwrap_or_else(|| Local::now().year()), method)
+            }
+            Command::Benchmark { since, to, list } => {
+                let benchmark = match read_benchmark() {
+                    Ok(benchmark) => benchmark,
+                    Err(e) => {
+                        println!("{}", e);
+                        return Ok(())
+                    }
+                };
+
+                let (from, to) = match parse_period(since, to) {
+                    Ok(period) => period,
+                    Err(e) => {
+                        println!("{}", e);
+                        return Ok(())
+                    }
+                };
+
+                let symbols = match list {
+                    Some(list) => watchlist_query::stocks(&list.to_lowercase()).unwrap_or_default(),
+                    None => file_query::symbols(File::open("config/stocks.txt").unwrap())
+                };
+                let transactions = transaction_query::list();
+
+                print_benchmark(benchmark, symbols, &transactions, from, to).await
+            }
+            Command::SetBenchmark { symbol } => {
+                match set_benchmark(symbol) {
+                    Ok(_) => println!("Benchmark was set!"),
+                    Err(e) => println!("Error occurred when trying to set benchmark: {}", e)
+                }
+            }
+            Command::SetLocale { locale } => {
+                match Locale::parse(&locale) {
+                    Some(locale) => match set_locale(locale) {
+                        Ok(_) => println!("Locale was set!"),
+                        Err(e) => println!("Error occurred when trying to set locale: {}", e)
+                    },
+                    None => println!("{}", LocaleError::UnknownLocale { locale })
+                }
+            }
+            Command::Screen { expression } => {
+                let expression = match Expression::parse(&expression) {
+                    Ok(expression) => expression,
+                    Err(e) => {
+                        println!("{}", e);
+                        return Ok(())
+                    }
+                };
+
+                let stocks: Vec<Stock> = file_query::stocks(File::open("config/stocks.txt").unwrap())
+                    .into_iter()
+                    .filter(|stock| expression.evaluate(stock))
+                    .collect();
+
+                print_screen(&expression, &stocks)
+            }
+            Command::Dcf { stock_name, fcf, shares, growth, years, terminal_growth, discount_rate } => {
+                let (fcf, shares) = match (
+                    fcf.as_deref().map(parse_amount), 
+                    shares.as_deref().map(parse_amount)
+                ) {
+                    (Some(None), _) | (_, Some(None)) => {
+                        println!("Free cash flow and shares need to be numbers like 100000000 or 100M.");
+                        return Ok(())
+                    },
+                    (fcf, shares) => (fcf.flatten(), shares.flatten())
+                };
+
+                let stock = match file_query::find(File::open("config/stocks.txt").unwrap(), &stock_name) {
+                    Some(stock) => stock,
+                    None => {
+                        println!("Stock was not found.");
+                        return Ok(())
+                    }
+                };
+                let assumptions = Assumptions { growth, years, terminal_growth, discount_rate };
+
+                print_dcf(&stock, fcf, shares, assumptions).await
+            }
+            Command::Financials { stock_name, statement, quarterly, update } => {
+                let kind = match StatementKind::parse(&statement) {
+                    Some(kind) => kind,
+                    None => {
+                        println!("Statement {} is not supported. Use income, balance or cashflow.", statement);
+                        return Ok(())
+                    }
+                };
+                let frequency = if quarterly { Frequency::Quarterly } else { Frequency::Annual };
+                let symbol = stock_name.to_lowercase();
+
+                let mut rows = statement_query::list(&symbol, kind, frequency);
+
+                if rows.is_empty() || update {
+                    rows = match fetch_statement(&symbol, kind, frequency).await {
+                        Ok(rows) => rows,
+                        Err(e) => {
+                            println!("{}", e);
+                            return Ok(())
+                        }
+                    };
+
+                    if let Err(e) = statement_query::save(&symbol, kind, frequency, &rows) {
+                        println!("Error occurred when saving statement: {}", e)
+                    }
+                }
+
+                print_statement(&symbol, kind, frequency, &rows)
+            }
+            Command::Score { stock_name, update } => {
+                let symbol = stock_name.to_lowercase();
+
+                for kind in [StatementKind::Income, StatementKind::Balance, StatementKind::CashFlow] {
+                    if update || statement_query::list(&symbol, kind, Frequency::Annual).is_empty() {
+                        match fetch_statement(&symbol, kind, Frequency::Annual).await {
+                            Ok(rows) => {
+                                if let Err(e) = statement_query::save(&symbol, kind, Frequency::Annual, &rows) {
+                                    println!("Error occurred when saving statement: {}", e)
+                                }
+                            },
+                            Err(e) => println!("{}", e)
+                        }
+                    }
+                }
+
+                let rows = statement_query::for_symbol(&symbol, Frequency::Annual);
+                let price = file_query::current_prices(File::open("config/stocks.txt").unwrap()).get(&symbol).copied();
+
+                print_scores(&symbol, &rows, price)
+            }
+            Command::Value { stock_name, growth, bond_yield } => {
+                match file_query::find(File::open("config/stocks.txt").unwrap(), &stock_name) {
+                    Some(stock) => print_value(&stock, growth, bond_yield),
+                    None => println!("Stock was not found.")
+                }
+            }
+            Command::Target { action } => {
+                match action {
+                    TargetCommand::Show {} => match read_targets() {
+                        Ok(targets) => print_targets(&targets),
+                        Err(e) => println!("{}", e)
+                    },
+                    TargetCommand::Set { name, weight, tag } => {
+                        if !(0.0..=100.0).contains(&weight) {
+                            println!("Weight has to be between 0 and 100.");
+                            return Ok(())
+                        }
+
+                        match set_target(name.to_lowercase(), Some(weight), tag) {
+                            Ok(_) => println!("Target was set!"),
+                            Err(e) => println!("Error occurred when trying to set target: {}", e)
+                        }
+                    },
+                    TargetCommand::Remove { name, tag } => {
+                        match set_target(name.to_lowercase(), None, tag) {
+                            Ok(_) => println!("Target was removed!"),
+                            Err(e) => println!("Error occurred when trying to remove target: {}", e)
+                        }
+                    }
+                }
+            }
+            Command::Rebalance { cash, min_trade, whole_shares } => {
+                let transactions = transaction_query::list();
+                let prices = file_query::current_prices(File::open("config/stocks.txt").unwrap());
+                let tags: HashMap<String, Vec<String>> = watchlist_query::all().into_iter().collect();
+
+                print_rebalance(&transactions, &prices, &tags, cash, min_trade, whole_shares)
+            }
+            Command::Info { explanation, stock_name, search } => {
+                if let Some(query) = search {
+                    print_search(&query, current_locale());
+                    return Ok(())
+                }
+
+                let term = match print_expl(&explanation, current_locale()) {
+                    Some(term) => term,
+                    None => return Ok(())
+                };
+
+                if let Some(stock_name) = stock_name {
+                    let file = File::open("config/stocks.txt").unwrap();
+
+                    match file_query::find(file, &stock_name) {
+                        Some(stock) => print_evaluation(&term, &stock, &statement_query::for_symbol(&stock.name, Frequency::Annual)),
+                        None => println!("Stock was not found.")
+                    }
+                }
             }
             Command::Search { stock_name } => {
                 let file = File::open("config/stocks.txt").unwrap();
 
-                file_query::search(file, stock_name.to_lowercase())
+                if file_query::search(file, stock_name.to_lowercase()) {
+                    println!();
+                    print_ratios(&statement_query::for_symbol(&stock_name.to_lowercase(), Frequency::Annual), current_locale())
+                }
             }
-            Command::Drop { stock_name } => {
+            Command::Drop { stock_name, list } => {
+                if let Some(list) = list {
+                    match watchlist_query::remove_stock(Some(&list.to_lowercase()), &stock_name.to_lowercase()) {
+                        Ok(true) => println!("Stock was removed from list {}!", list),
+                        Ok(false) => println!("Stock was not found in list {}.", list),
+                        Err(e) => println!("Error occurred when removing stock from list: {}", e)
+                    }
+
+                    return Ok(())
+                }
+
                 let file = File::open("config/stocks.txt").unwrap();
                 
-                file_query::drop(file, stock_name)
+                file_query::drop(file, stock_name.clone());
+
+                if let Err(e) = watchlist_query::remove_stock(None, &stock_name.to_lowercase()) {
+                    println!("Error occurred when removing stock from lists: {}", e)
+                }
             }
             Command::Update { stock_name } => {
                 let file = File::open("config/stocks.txt").unwrap();
 
-                file_query::update(file, stock_name).await
+                if let Err(e) = file_query::update(file, stock_name.clone()).await {
+                    println!("Stock could not be updated: {}. {}", stock_name.to_uppercase(), e)
+                }
             }
-            Command::UpdateAll {} => {
+            Command::UpdateAll { list: None } => {
                 let file = File::open("config/stocks.txt").unwrap();
 
-                file_query::update_all(file).await
+                file_query::update_all(file).await;
             }
-            Command::History { stock_name, date } => {
+            Command::UpdateAll { list: Some(list) } => {
+                let stocks = match watchlist_query::stocks(&list.to_lowercase()) {
+                    Some(stocks) => stocks,
+                    None => {
+                        println!("List {} was not found.", list);
+                        return Ok(())
+                    }
+                };
+
+                for stock_name in stocks {
+                    let file = File::open("config/stocks.txt").unwrap();
+
+                    if let Err(e) = file_query::update(file, stock_name.clone()).await {
+                        println!("Stock could not be updated: {}. {}", stock_name.to_uppercase(), e)
+                    }
+                }
+
+                println!("Updating of stocks done!");
+            }
+            Command::Watch { every, market_hours_only } => {
+                watch(
+                    &mut (),
+                    &every,
+                    market_hours_only,
+                    async |_: &mut ()| Ok(file_query::update_all(File::open("config/stocks.txt").unwrap()).await),
+                    async |_: &mut ()| Ok(alert_query::list()),
+                    async |_: &mut ()| Ok(file_query::stocks(File::open("config/stocks.txt").unwrap())),
+                ).await
+            }
+            Command::History { stock_name, date, interval } => {
                 let contents = file_query::file_to_string(&File::open("config/stocks.txt").unwrap());
 
                 let mut symbol_exists = false;
@@ -96,18 +585,97 @@ pub mod cmd {
                     return Ok(())
                 }
     
-                let splitted_date = split_date(date);
+                let splitted_date = match split_date(date) {
+                    Ok(splitted_date) => splitted_date,
+                    Err(e) => {
+                        println!("{}", e);
+                        return Ok(())
+                    }
+                };
 
                 let current_price = stock_row.split(" ").collect::<Vec<&str>>()[2].parse::<f64>().unwrap();
 
-                print_history_price(stock_name, splitted_date, current_price).await
+                print_history_price(stock_name, splitted_date, current_price, interval, current_locale()).await
+            }
+            Command::Stats { stock_name, from, to, risk_free, json } => {
+                print_stats(stock_name, from, to, risk_free, json).await
+            }
+            Command::Compare { stock_names, since, normalise } => {
+                print_comparison(stock_names, since, normalise).await
             }
             Command::Init {} => init_mode().await,
             Command::ShowDB { .. } => println!("This command is only available if mode is set to database."),
             Command::SetDB { .. } => println!("This command is only available if mode is set to database."),
+            Command::Migrate { .. } => println!("This command is only available if mode is set to database."),
             Command::Mode {} => println!("File")
         }
     
         Ok(())
     }
+
+    /// Validates the symbol against Yahoo's lookup page and saves the stock.
+    async fn add_stock(stock_name: String) -> bool {
+        let file = OpenOptions::new()
+                        .append(true)
+                        .open("config/stocks.txt")
+                        .unwrap();
+
+        let url = stock_scraper::Url { symbol: stock_name.clone() };
+
+        let selector = ::scraper::Selector::parse("section[id='lookup-page']");
+
+        match selector {
+            Ok(selector_parse) => {
+                let uri = match url.scrapped_home().await {
+                    Ok(uri) => uri,
+                    Err(e) => {
+                        println!("{}", e);
+                        return false
+                    }
+                };
+                let html = uri.select(&selector_parse).next();
+
+                if html.is_none() {
+                    match file_query::insert(&file, stock_name, false).await {
+                        Ok(_) => return true,
+                        Err(e) => println!("Error occurred when adding stock: {}", e)
+                    }
+                } else {
+                    println!("Stock symbol is not valid. Make sure that it exists.");
+                }
+            },
+            Err(e) => println!("Error occurred when trying to parse page: {:?}", e)
+        }
+
+        false
+    }
+
+    fn record_transaction(
+        kind: Kind, stock_name: String, quantity: f64, price: f64, date: Option<String>, fee: f64
+    ) {
+        let transaction = match Transaction::new(kind, stock_name, quantity, price, date, fee) {
+            Ok(transaction) => transaction,
+            Err(e) => return println!("{}", e)
+        };
+
+        let transactions = transaction_query::list();
+
+        if let Err(e) = validate(&transactions, &transaction) {
+            return println!("{}", e)
+        }
+
+        match transaction_query::insert(&transaction) {
+            Ok(_) => println!("Transaction was recorded!"),
+            Err(e) => return println!("Error occurred when recording transaction: {}", e)
+        }
+
+        let prices = file_query::current_prices(File::open("config/stocks.txt").unwrap());
+
+        if !prices.contains_key(&transaction.symbol) {
+            println!(
+                "Stock {} is not in your stocks yet. Run 'add {}' so the portfolio can use its current price.", 
+                transaction.symbol.to_uppercase(), transaction.symbol
+            );
+        }
+    }
 }
\ No newline at end of file
@@ -1,30 +1,51 @@
 pub mod queries {
-    use std::{io::{Write, Read, BufReader}, fs::File};
+    use std::{io::{Write, Read, BufReader}, fs::File, collections::HashMap};
 
+    use crate::database::queries::queries::{Stock, print_stock};
+    use crate::errors::error_handler::error_handler::YahooError;
+    use crate::output::locale::locale::current_locale;
+    use crate::portfolio::allocation::allocation::Classification;
     use crate::scraper::financial_data::get_financial_data as stock_scraper;
 
-    pub fn list(file: std::fs::File) {
+    pub fn list(file: std::fs::File, members: Option<Vec<String>>) -> Vec<Stock> {
+        stocks(file).into_iter()
+            .filter(|stock| match &members {
+                Some(members) => members.contains(&stock.name.to_lowercase()),
+                None => true
+            })
+            .collect()
+    }
+
+    pub fn symbols(file: std::fs::File) -> Vec<String> {
         let contents = file_to_string(&file);
 
-        for row in contents.split(";") {
-            let fields = row.split(",").collect::<Vec<&str>>();
-            let symbol = fields[0].replace("\n", "");
+        contents.split(";")
+            .map(|row| row.split(",").next().unwrap_or("").replace("\n", "").to_lowercase())
+            .filter(|symbol| !symbol.is_empty())
+            .collect()
+    }
 
-            println!("{}", symbol.to_uppercase());
-        }
+    pub async fn insert(file: &std::fs::File, name: String, update: bool) -> Result<(), YahooError> {
+        let row = scrape_row(&name).await?;
+        write_row(file, &name, row, update);
+
+        Ok(())
     }
 
-    pub async fn insert(mut file: &std::fs::File, name: String, update: bool) {
-        let url = stock_scraper::Url { symbol: name.clone() };
+    /// Scrapes a stock into a row of stocks.txt.
+    async fn scrape_row(name: &str) -> Result<String, YahooError> {
+        let url = stock_scraper::Url { symbol: name.to_string() };
         
-        let uri = url.scrapped_home().await;
-        let uri_statistic = url.scrapped_statistics().await;
+        let uri = url.scrapped_home().await?;
+        let uri_statistic = url.scrapped_statistics().await?;
+        let uri_profile = url.scrapped_profile().await?;
 
-        let stock_data = stock_scraper::StockData { symbol: name.clone(), url: uri };
-        let stock_data_statistic = stock_scraper::StockData { symbol: name.clone(), url: uri_statistic };
+        let stock_data = stock_scraper::StockData { symbol: name.to_string(), url: uri };
+        let stock_data_statistic = stock_scraper::StockData { symbol: name.to_string(), url: uri_statistic };
+        let stock_data_profile = stock_scraper::StockData { symbol: name.to_string(), url: uri_profile };
 
         let name = format!("{},", &name);
-        let current_price = format!("Current Price: {} {},", &stock_data.current_price(), &stock_data.change_since());
+        let current_price = format!("Current Price: {} {},", &stock_data.current_price()?, &stock_data.change_since());
         let trailing_eps = format!("EPS: {},", &stock_data.trailing_eps());
         let pe = format!("P/E Ratio: {},", &stock_data.pe_ratio());
         let debt_equity = format!("Debt to Equity Ratio: {},", &stock_data_statistic.debt_equity_ratio());
@@ -37,26 +58,31 @@ pub mod queries {
         let total_debt = format!("Total Debt: {},", &stock_data_statistic.total_debt());
         let return_on_equity = format!("Return on Equity: {},", &stock_data_statistic.return_on_equity());
         let return_on_assets = format!("Return on Assets: {},", &stock_data_statistic.return_on_assets());
-        let bvps = format!("Book Value per Share: {};", &stock_data_statistic.bvps());
+        let bvps = format!("Book Value per Share: {},", &stock_data_statistic.bvps());
+        let sector = format!("Sector: {},", &stock_data_profile.sector());
+        let industry = format!("Industry: {},", &stock_data_profile.industry());
+        let country = format!("Country: {},", &stock_data_profile.country());
+        let currency = format!("Currency: {};", &stock_data.currency());
 
-        let cloned_name = name.clone();
-
-        let mut row = name + current_price.as_str() + trailing_eps.as_str() + pe.as_str() + 
+        Ok(name + current_price.as_str() + trailing_eps.as_str() + pe.as_str() + 
             debt_equity.as_str() + market_cap.as_str() + peg.as_str() +
             price_to_book.as_str() + revenue.as_str() + gross_profit.as_str() + 
             total_cash.as_str() + total_debt.as_str() + return_on_equity.as_str() + 
-            return_on_assets.as_str() + bvps.as_str();
+            return_on_assets.as_str() + bvps.as_str() + sector.as_str() + 
+            industry.as_str() + country.as_str() + currency.as_str())
+    }
 
+    fn write_row(mut file: &std::fs::File, name: &str, mut row: String, update: bool) {
         if update {
             match file.write_all(row.as_bytes()) {
-                Ok(_) => println!("Stock {} was updated!", cloned_name.split(",").collect::<Vec<&str>>()[0].to_uppercase()),
+                Ok(_) => println!("Stock {} was updated!", name.to_uppercase()),
                 Err(e) => {
                     println!("Error occurred when updating stock.");
                     println!("Error: {}", e);
                 }
             }
         } else {
-            row = row + "\n";
+            row += "\n";
             match file.write_all(row.as_bytes()) {
                 Ok(_) => println!("Stock was added!"),
                 Err(e) => {
@@ -83,7 +109,7 @@ pub mod queries {
             
             let row = row.to_string() + ";";
 
-            if symbol != name && symbol != "" {
+            if symbol != name && !symbol.is_empty() {
                 file.write_all(row.as_bytes()).unwrap();
             } else if symbol == name {
                 symbol_found = true;
@@ -99,14 +125,31 @@ pub mod queries {
         }
     }
 
-    pub async fn update(file: std::fs::File, name: String) {
+    /// Updates a stock and returns whether it was found. The file is only rewritten once
+    /// the stock was scraped, so a failed scrape keeps the saved values.
+    pub async fn update(file: std::fs::File, name: String) -> Result<bool, YahooError> {
         let contents = file_to_string(&file);
 
+        let symbol_found = contents.split(";")
+            .map(|row| row.split(",").next().unwrap_or("").replace("\n", ""))
+            .find(|symbol| symbol.eq_ignore_ascii_case(&name));
+
+        // The stock is saved again with its symbol as it was stored, whatever the case of the name.
+        let (stored_symbol, updated_row) = match symbol_found {
+            Some(symbol) => {
+                let row = scrape_row(&symbol).await?;
+                (symbol, row)
+            },
+            None => {
+                println!("Stock was not found.");
+                return Ok(false)
+            }
+        };
+
         let mut file = File::create("config/stocks.txt").unwrap();
 
         file.write_all("".as_bytes()).unwrap();
 
-        let mut symbol_found = false;
         let mut stock_to_update_is_first = false;        
 
         for (i, row) in contents.split(";").enumerate() {
@@ -115,7 +158,7 @@ pub mod queries {
 
             let row = row.to_string() + ";";
 
-            if symbol != name && symbol != "" {
+            if !symbol.eq_ignore_ascii_case(&name) && !symbol.is_empty() {
                 if stock_to_update_is_first {
                     let (_, formatted_row) = row.split_at(1);
                     file.write_all(formatted_row.as_bytes()).unwrap();
@@ -123,67 +166,186 @@ pub mod queries {
                 } else {
                     file.write_all(row.as_bytes()).unwrap();
                 }
-            } else if symbol == name {
-                if i == 0 {
-                    stock_to_update_is_first = true
-                }
-
-                symbol_found = true;
+            } else if symbol.eq_ignore_ascii_case(&name) && i == 0 {
+                stock_to_update_is_first = true
             }
         }
 
-        if symbol_found {
-            file.write_all("\n".as_bytes()).unwrap();
-            insert(&file, name, true).await;
-        } else {
-            println!("Stock was not found.");
-        }
+        file.write_all("\n".as_bytes()).unwrap();
+        write_row(&file, &stored_symbol, updated_row, true);
 
         file.write_all("\n".as_bytes()).unwrap();
+        Ok(true)
     }
 
-    pub async fn update_all(file: std::fs::File) {
+    /// Stocks which can't be scraped keep their saved values. Returns their symbols.
+    pub async fn update_all(file: std::fs::File) -> Vec<String> {
         let contents = file_to_string(&file);
+        let mut rows = Vec::new();
+        let mut failed = Vec::new();
+
+        for row in contents.split(";") {
+            let fields = row.split(",").collect::<Vec<&str>>();
+            let symbol = fields[0].replace("\n", "");
+
+            if !symbol.is_empty() {
+                match scrape_row(&symbol).await {
+                    Ok(updated_row) => rows.push((symbol, updated_row, true)),
+                    Err(e) => {
+                        println!("Stock could not be updated: {}. {}", symbol.to_uppercase(), e);
+                        failed.push(symbol.clone());
+                        rows.push((symbol, row.trim().to_string() + ";", false))
+                    }
+                }
+            } 
+        }
 
         let mut file = File::create("config/stocks.txt").unwrap();
 
         file.write_all("".as_bytes()).unwrap();
 
+        for (symbol, row, updated) in rows {
+            match updated {
+                true => write_row(&file, &symbol, row, true),
+                false => file.write_all(row.as_bytes()).unwrap()
+            }
+            file.write_all("\n".as_bytes()).unwrap();
+        }
+
+        println!("Updating of stocks done!");
+
+        failed
+    }
+
+    pub fn search(file: std::fs::File, name: String) -> bool {
+        let locale = current_locale();
+
+        match find(file, &name) {
+            Some(stock) => {
+                print_stock(&stock, locale);
+                true
+            },
+            None => {
+                println!("{}", locale.label("Stock was not found."));
+                false
+            }
+        }
+    }
+
+    pub fn file_to_string(file: &std::fs::File) -> String {
+        let mut contents = String::new();
+        let mut buf_reaer = BufReader::new(file);
+        buf_reaer.read_to_string(&mut contents).unwrap();
+
+        contents
+    }
+
+    pub fn current_prices(file: std::fs::File) -> HashMap<String, f64> {
+        let contents = file_to_string(&file);
+        let mut prices = HashMap::new();
+
         for row in contents.split(";") {
             let fields = row.split(",").collect::<Vec<&str>>();
             let symbol = fields[0].replace("\n", "");
 
-            if symbol != "" {
-                insert(&file, symbol, true).await;
-                file.write_all("\n".as_bytes()).unwrap();
-            } 
+            let price = fields.iter()
+                .find_map(|field| field.strip_prefix("Current Price: "))
+                .and_then(|value| value.split(" ").next())
+                .and_then(|value| value.parse::<f64>().ok());
+
+            if let Some(price) = price {
+                prices.insert(symbol.to_lowercase(), price);
+            }
         }
 
-        println!("Updating of stocks done!");
+        prices
     }
 
-    pub fn search(file: std::fs::File, name: String) {
+    /// Sector, industry, country and currency per stock. Rows written before these 
+    /// were scraped are reported as Unknown.
+    pub fn classifications(file: std::fs::File) -> HashMap<String, Classification> {
         let contents = file_to_string(&file);
+        let mut classifications = HashMap::new();
 
         for row in contents.split(";") {
             let fields = row.split(",").collect::<Vec<&str>>();
             let symbol = fields[0].replace("\n", "");
 
-            if symbol == name {
-                println!("Stock: {}", name.to_uppercase());
-                for field in fields.iter().skip(1) {
-
-                    println!("  - {}", field);
-                }
+            if symbol.is_empty() {
+                continue
             }
+
+            let value = |label: &str| fields.iter()
+                .find_map(|field| field.strip_prefix(label))
+                .map(|value| value.trim().to_string())
+                .unwrap_or_else(|| "Unknown".to_string());
+
+            classifications.insert(symbol.to_lowercase(), Classification {
+                sector: value("Sector: "),
+                industry: value("Industry: "),
+                country: value("Country: "),
+                currency: value("Currency: "),
+            });
         }
+
+        classifications
     }
 
-    pub fn file_to_string(file: &std::fs::File) -> String {
-        let mut contents = String::new();
-        let mut buf_reaer = BufReader::new(file);
-        buf_reaer.read_to_string(&mut contents).unwrap();
+    /// Parses every row of the stocks file. Missing or unreadable values are left at their default.
+    pub fn stocks(file: std::fs::File) -> Vec<Stock> {
+        let contents = file_to_string(&file);
 
-        contents
+        contents.split(";")
+            .filter_map(parse_stock)
+            .collect()
+    }
+
+    pub fn find(file: std::fs::File, name: &str) -> Option<Stock> {
+        stocks(file).into_iter().find(|stock| stock.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn parse_stock(row: &str) -> Option<Stock> {
+        let fields = row.split(",").collect::<Vec<&str>>();
+        let symbol = fields[0].replace("\n", "").trim().to_string();
+
+        if symbol.is_empty() {
+            return None
+        }
+
+        let text = |label: &str| fields.iter()
+            .find_map(|field| field.strip_prefix(label))
+            .map(|value| value.trim().to_string())
+            .unwrap_or_default();
+        let number = |label: &str| text(label).parse::<f64>().unwrap_or(0.0);
+
+        let current_price = text("Current Price: ");
+        let (price, change_since) = current_price.split_once(' ').unwrap_or((&current_price, ""));
+        let classification = |label: &str| match text(label) {
+            value if value.is_empty() => "Unknown".to_string(),
+            value => value
+        };
+
+        Some(Stock {
+            name: symbol,
+            current_price: price.parse::<f64>().unwrap_or(0.0),
+            eps_ttm: number("EPS: "),
+            pe_ratio: number("P/E Ratio: "),
+            total_debt_equity: number("Debt to Equity Ratio: "),
+            change_since: change_since.to_string(),
+            market_cap: text("Market Cap: "),
+            peg_ratio: number("PEG Ratio: "),
+            price_to_book: number("Price to Book: "),
+            revenue: text("Revenue: "),
+            gross_profit: text("Gross Profit: "),
+            total_cash: text("Total Cash: "),
+            total_debt: text("Total Debt: "),
+            return_on_equity: text("Return on Equity: "),
+            return_on_assets: text("Return on Assets: "),
+            bvps: number("Book Value per Share: "),
+            sector: classification("Sector: "),
+            industry: classification("Industry: "),
+            country: classification("Country: "),
+            currency: classification("Currency: "),
+        })
     }
-}
+}
\ No newline at end of file
@@ -0,0 +1,85 @@
+pub mod statements {
+    use std::{fs::{File, OpenOptions}, io::Write};
+
+    use crate::file::queries::queries::file_to_string;
+    use crate::statements::statements::statements::{Frequency, Kind, StatementRow};
+
+    const STATEMENTS_FILE: &str = "config/statements.txt";
+
+    /// Replaces the stored statement of the symbol with the given rows.
+    pub fn save(symbol: &str, kind: Kind, frequency: Frequency, rows: &[StatementRow]) -> std::io::Result<()> {
+        let kept: Vec<StatementRow> = all().into_iter()
+            .filter(|row| !(row.symbol == symbol && row.kind == kind && row.frequency == frequency))
+            .collect();
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(STATEMENTS_FILE)?;
+
+        for row in kept.iter().chain(rows.iter()) {
+            let line = format!(
+                "{},{},{},{},{},{};\n",
+                row.symbol,
+                row.kind.as_str(),
+                row.frequency.as_str(),
+                row.period,
+                row.item,
+                row.value,
+            );
+
+            file.write_all(line.as_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    pub fn list(symbol: &str, kind: Kind, frequency: Frequency) -> Vec<StatementRow> {
+        let mut rows: Vec<StatementRow> = all().into_iter()
+            .filter(|row| row.symbol == symbol && row.kind == kind && row.frequency == frequency)
+            .collect();
+        rows.sort_by(|a, b| a.period.cmp(&b.period));
+
+        rows
+    }
+
+    /// All stored statements of the symbol with the given frequency.
+    pub fn for_symbol(symbol: &str, frequency: Frequency) -> Vec<StatementRow> {
+        let mut rows: Vec<StatementRow> = all().into_iter()
+            .filter(|row| row.symbol == symbol && row.frequency == frequency)
+            .collect();
+        rows.sort_by(|a, b| a.period.cmp(&b.period));
+
+        rows
+    }
+
+    fn all() -> Vec<StatementRow> {
+        let file = match File::open(STATEMENTS_FILE) {
+            Ok(file) => file,
+            Err(_) => return vec![]
+        };
+
+        file_to_string(&file)
+            .split(';')
+            .filter_map(parse_row)
+            .collect()
+    }
+
+    fn parse_row(row: &str) -> Option<StatementRow> {
+        let fields = row.trim().split(',').collect::<Vec<&str>>();
+
+        if fields.len() < 6 {
+            return None
+        }
+
+        Some(StatementRow {
+            symbol: fields[0].to_string(),
+            kind: Kind::parse(fields[1])?,
+            frequency: Frequency::parse(fields[2])?,
+            period: fields[3].to_string(),
+            item: fields[4].to_string(),
+            value: fields[5].parse::<f64>().ok()?,
+        })
+    }
+}
@@ -0,0 +1,62 @@
+pub mod transactions {
+    use std::{fs::{File, OpenOptions}, io::Write};
+
+    use chrono::NaiveDate;
+
+    use crate::file::queries::queries::file_to_string;
+    use crate::portfolio::transactions::transactions::{Kind, Transaction, DATE_FORMAT};
+
+    const TRANSACTIONS_FILE: &str = "config/transactions.txt";
+
+    pub fn insert(transaction: &Transaction) -> std::io::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(TRANSACTIONS_FILE)?;
+
+        let row = format!(
+            "{},{},{},{},{},{};\n",
+            transaction.symbol,
+            transaction.kind.as_str(),
+            transaction.quantity,
+            transaction.price,
+            transaction.fee,
+            transaction.date.format(DATE_FORMAT),
+        );
+
+        file.write_all(row.as_bytes())
+    }
+
+    pub fn list() -> Vec<Transaction> {
+        let file = match File::open(TRANSACTIONS_FILE) {
+            Ok(file) => file,
+            Err(_) => return vec![]
+        };
+
+        let contents = file_to_string(&file);
+
+        let mut transactions: Vec<Transaction> = contents.split(';')
+            .filter_map(parse_row)
+            .collect();
+        transactions.sort_by_key(|transaction| transaction.date);
+
+        transactions
+    }
+
+    fn parse_row(row: &str) -> Option<Transaction> {
+        let fields = row.trim().split(',').collect::<Vec<&str>>();
+
+        if fields.len() < 6 {
+            return None
+        }
+
+        Some(Transaction {
+            symbol: fields[0].to_string(),
+            kind: Kind::parse(fields[1])?,
+            quantity: fields[2].parse::<f64>().ok()?,
+            price: fields[3].parse::<f64>().ok()?,
+            fee: fields[4].parse::<f64>().ok()?,
+            date: NaiveDate::parse_from_str(fields[5], DATE_FORMAT).ok()?,
+        })
+    }
+}
@@ -0,0 +1,101 @@
+pub mod watchlists {
+    use std::{fs::File, io::Write};
+
+    use crate::file::queries::queries::file_to_string;
+
+    const WATCHLISTS_FILE: &str = "config/lists.txt";
+
+    /// Every list is saved as one row: `name:symbol,symbol;`
+    pub fn all() -> Vec<(String, Vec<String>)> {
+        let file = match File::open(WATCHLISTS_FILE) {
+            Ok(file) => file,
+            Err(_) => return vec![]
+        };
+
+        file_to_string(&file)
+            .split(';')
+            .filter_map(|row| {
+                let (name, stocks) = row.trim().split_once(':')?;
+                let stocks = stocks.split(',')
+                    .filter(|stock| !stock.is_empty())
+                    .map(|stock| stock.to_string())
+                    .collect();
+
+                Some((name.to_string(), stocks))
+            })
+            .collect()
+    }
+
+    pub fn stocks(name: &str) -> Option<Vec<String>> {
+        all().into_iter()
+            .find(|(list_name, _)| list_name == name)
+            .map(|(_, stocks)| stocks)
+    }
+
+    pub fn create(name: String) -> Result<bool, std::io::Error> {
+        let mut lists = all();
+
+        if lists.iter().any(|(list_name, _)| *list_name == name) {
+            return Ok(false)
+        }
+
+        lists.push((name, vec![]));
+        save(&lists)?;
+
+        Ok(true)
+    }
+
+    pub fn delete(name: &str) -> Result<bool, std::io::Error> {
+        let mut lists = all();
+        let count = lists.len();
+
+        lists.retain(|(list_name, _)| list_name != name);
+        save(&lists)?;
+
+        Ok(lists.len() < count)
+    }
+
+    pub fn add_stock(name: &str, stock_name: String) -> Result<bool, std::io::Error> {
+        let mut lists = all();
+
+        let added = match lists.iter_mut().find(|(list_name, _)| list_name == name) {
+            Some((_, stocks)) if !stocks.contains(&stock_name) => {
+                stocks.push(stock_name);
+                true
+            },
+            _ => false
+        };
+
+        save(&lists)?;
+
+        Ok(added)
+    }
+
+    pub fn remove_stock(name: Option<&str>, stock_name: &str) -> Result<bool, std::io::Error> {
+        let mut lists = all();
+        let mut removed = false;
+
+        for (list_name, stocks) in lists.iter_mut() {
+            if name.is_none() || name == Some(list_name.as_str()) {
+                let count = stocks.len();
+                stocks.retain(|stock| stock != stock_name);
+                removed = removed || stocks.len() < count;
+            }
+        }
+
+        save(&lists)?;
+
+        Ok(removed)
+    }
+
+    fn save(lists: &[(String, Vec<String>)]) -> Result<(), std::io::Error> {
+        let mut file = File::create(WATCHLISTS_FILE)?;
+
+        for (name, stocks) in lists {
+            let row = format!("{}:{};\n", name, stocks.join(","));
+            file.write_all(row.as_bytes())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,283 @@
+pub mod evaluation {
+    use crate::database::queries::queries::{Stock, format_amount};
+    use crate::statements::ratios::ratios::ratios;
+    use crate::statements::statements::statements::{StatementRow, balance_sheets, income_statements};
+    use crate::valuation::graham::graham::implied_growth;
+
+    /// Terms which use values of the saved annual statements.
+    const STATEMENT_TERMS: &[&str] = &[
+        "bvps", "debt_equity_ratio", "return_on_equity", "return_on_assets", "market_value",
+        "current_ratio", "assets", "liabilities", "equity",
+        "cash_from_operating_activities", "cash_from_investing_activities", "cash_from_financing_activities",
+    ];
+
+    /// A term of `info` applied to the values of a stock.
+    #[derive(Debug, Clone)]
+    pub struct Evaluation {
+        pub value: String,
+        pub computation: Option<String>,
+        /// The range the explanation describes as good, if it describes one.
+        pub range: Option<&'static str>,
+        /// None if values to judge the range are missing.
+        pub verdict: Option<String>,
+    }
+
+    fn stored(stock: &Stock, field: &str) -> Option<f64> {
+        stock.field(field).filter(|value| *value != 0.0)
+    }
+
+    fn divide(numerator: Option<f64>, denominator: Option<f64>) -> Option<f64> {
+        match (numerator, denominator) {
+            (Some(numerator), Some(denominator)) if denominator != 0.0 => Some(numerator / denominator),
+            _ => None
+        }
+    }
+
+    fn ratio(value: Option<f64>) -> String {
+        value.map(|value| format!("{:.2}", value)).unwrap_or_else(|| "-".to_string())
+    }
+
+    fn percent(value: Option<f64>) -> String {
+        value.map(|value| format!("{:.2}%", value)).unwrap_or_else(|| "-".to_string())
+    }
+
+    fn amount(value: Option<f64>) -> String {
+        value.map(format_amount).unwrap_or_else(|| "-".to_string())
+    }
+
+    /// "higher" or "lower" than the prior period, None if either is missing.
+    fn trend(current: Option<f64>, prior: Option<f64>, period: &str) -> Option<String> {
+        match (current, prior) {
+            (Some(current), Some(prior)) if current > prior => Some(format!("higher than in {}", period)),
+            (Some(current), Some(prior)) if current < prior => Some(format!("lower than in {}", period)),
+            (Some(_), Some(_)) => Some(format!("the same as in {}", period)),
+            _ => None
+        }
+    }
+
+    /// Evaluates the term with the stored values of the stock. Terms computed from the
+    /// financial statements take the latest annual period of `rows`.
+    /// Returns None for terms which aren't linked to values of a stock.
+    pub fn evaluate(term: &str, stock: &Stock, rows: &[StatementRow]) -> Option<Evaluation> {
+        let periods = ratios(rows);
+        let latest = periods.last().cloned().unwrap_or_default();
+        let prior = if periods.len() > 1 { periods.get(periods.len() - 2).cloned() } else { None };
+        let prior_period = prior.as_ref().map(|prior| prior.period.clone()).unwrap_or_default();
+        let balance = balance_sheets(rows).pop().unwrap_or_default();
+        let income = income_statements(rows).pop().unwrap_or_default();
+
+        let price = stored(stock, "current_price");
+        let eps = stored(stock, "eps_ttm");
+        let pe_ratio = stored(stock, "pe_ratio");
+        let peg_ratio = stored(stock, "peg_ratio");
+        let bvps = stored(stock, "bvps");
+        let return_on_assets = stored(stock, "return_on_assets");
+        let fiscal_year = |value: String| match latest.period.is_empty() {
+            true => value,
+            false => format!("{} (fiscal year ending {})", value, latest.period)
+        };
+
+        let evaluation = match term {
+            "pe_ratio" => Evaluation {
+                value: ratio(pe_ratio),
+                computation: Some(format!("current_price / eps_ttm = {} / {} = {}", ratio(price), ratio(eps), ratio(divide(price, eps)))),
+                range: Some("The lower, the better. A fairly priced company has a P/E equal to its growth rate."),
+                verdict: match (eps, pe_ratio, implied_growth(stock.pe_ratio, stock.peg_ratio)) {
+                    (Some(eps), _, _) if eps < 0.0 => Some("EPS is negative, so the P/E ratio says nothing about the price.".to_string()),
+                    (_, Some(pe_ratio), Some(growth)) if pe_ratio <= growth => Some(format!("At or below the growth rate of {:.2}% implied by the PEG ratio, so fairly priced or cheaper.", growth)),
+                    (_, Some(_), Some(growth)) => Some(format!("Above the growth rate of {:.2}% implied by the PEG ratio, so priced above its growth.", growth)),
+                    _ => None
+                },
+            },
+            "pb_ratio" => Evaluation {
+                value: ratio(stored(stock, "price_to_book")),
+                computation: Some(format!("current_price / bvps = {} / {} = {}", ratio(price), ratio(bvps), ratio(divide(price, bvps)))),
+                range: Some("Under 1 is typically considered a solid investment."),
+                verdict: stored(stock, "price_to_book").map(|pb_ratio| match pb_ratio < 1.0 {
+                    true => "Under 1. Check that the earnings aren't very low as well.".to_string(),
+                    false => "Not under 1.".to_string()
+                }),
+            },
+            "bvps" => Evaluation {
+                value: ratio(bvps),
+                computation: match balance.stockholders_equity.is_some() && balance.shares_outstanding.is_some() {
+                    true => Some(format!(
+                        "stockholders_equity / shares_outstanding = {} / {} = {} (fiscal year ending {})",
+                        amount(balance.stockholders_equity), amount(balance.shares_outstanding),
+                        ratio(divide(balance.stockholders_equity, balance.shares_outstanding)), balance.period
+                    )),
+                    false => None
+                },
+                range: Some("The stock is considered undervalued if the BVPS is higher than the current price."),
+                verdict: match (bvps, price) {
+                    (Some(bvps), Some(price)) if bvps > price => Some(format!("Higher than the current price of {:.2}, so undervalued.", price)),
+                    (Some(_), Some(price)) => Some(format!("Not higher than the current price of {:.2}.", price)),
+                    _ => None
+                },
+            },
+            "peg_ratio" => Evaluation {
+                value: ratio(peg_ratio),
+                computation: Some(format!(
+                    "pe_ratio / earnings growth = {} / {} (the growth isn't stored, this is the growth the PEG ratio implies)",
+                    ratio(pe_ratio), percent(implied_growth(stock.pe_ratio, stock.peg_ratio))
+                )),
+                range: Some("1 or lower says that the stock is at par or undervalued, based on its growth rate."),
+                verdict: peg_ratio.map(|peg_ratio| match peg_ratio <= 1.0 {
+                    true => "1 or lower, so at par or undervalued.".to_string(),
+                    false => "Above 1, so overvalued based on its growth rate.".to_string()
+                }),
+            },
+            "debt_equity_ratio" => Evaluation {
+                value: percent(stored(stock, "total_debt_equity")),
+                computation: Some(format!(
+                    "total_debt / equity = {} / {} = {}",
+                    amount(stored(stock, "total_debt")), amount(latest.equity),
+                    percent(divide(stored(stock, "total_debt"), latest.equity).map(|ratio| ratio * 100.0))
+                )),
+                range: Some("The lower, the better."),
+                verdict: trend(latest.debt_equity_ratio, prior.as_ref().and_then(|prior| prior.debt_equity_ratio), &prior_period)
+                    .map(|trend| format!("{} in the fiscal year ending {}, {}.", percent(latest.debt_equity_ratio), latest.period, trend)),
+            },
+            "return_on_equity" => Evaluation {
+                value: percent(stored(stock, "return_on_equity")),
+                computation: Some(fiscal_year(format!(
+                    "net_income / equity = {} / {} = {}",
+                    amount(income.net_income), amount(latest.equity), percent(latest.return_on_equity)
+                ))),
+                range: Some("The higher, the better. Too high can mean that the company has been borrowing aggressively."),
+                verdict: match (stored(stock, "return_on_equity"), return_on_assets) {
+                    (Some(return_on_equity), _) if return_on_equity <= 0.0 => Some("Not positive, the company doesn't generate profits from its equity.".to_string()),
+                    (Some(return_on_equity), Some(return_on_assets)) if return_on_equity > 2.0 * return_on_assets.abs() => Some(format!(
+                        "Positive, but more than twice the return on assets of {}. A large part of it comes from debt.", percent(Some(return_on_assets))
+                    )),
+                    (Some(_), Some(return_on_assets)) => Some(format!("Positive and close to the return on assets of {}, so not driven by debt.", percent(Some(return_on_assets)))),
+                    (Some(_), None) => Some("Positive.".to_string()),
+                    _ => None
+                },
+            },
+            "return_on_assets" => Evaluation {
+                value: percent(return_on_assets),
+                computation: Some(fiscal_year(format!(
+                    "net_income / total_assets = {} / {} = {}",
+                    amount(income.net_income), amount(latest.assets), percent(latest.return_on_assets)
+                ))),
+                range: Some("The higher, the better. It can only be compared within an industry."),
+                verdict: return_on_assets.map(|return_on_assets| match return_on_assets > 0.0 {
+                    true => format!("Positive. Compare it with other stocks of the {} industry.", stock.industry),
+                    false => "Not positive, the company doesn't generate profits from its assets.".to_string()
+                }),
+            },
+            "current_ratio" => Evaluation {
+                value: fiscal_year(ratio(latest.current_ratio)),
+                computation: Some(format!(
+                    "current_assets / current_liabilities = {} / {} = {}",
+                    amount(balance.current_assets), amount(balance.current_liabilities), ratio(latest.current_ratio)
+                )),
+                range: Some("The higher, the better. > 1.50 is good."),
+                verdict: latest.current_ratio.map(|current_ratio| match current_ratio > 1.5 {
+                    true => "Above 1.50, so good.".to_string(),
+                    false => "Not above 1.50.".to_string()
+                }),
+            },
+            "assets" | "liabilities" => Evaluation {
+                value: fiscal_year(match term {
+                    "assets" => format!("{} total, {} current", amount(balance.total_assets), amount(balance.current_assets)),
+                    _ => format!("{} total, {} current", amount(balance.total_liabilities), amount(balance.current_liabilities)),
+                }),
+                computation: None,
+                range: Some("Total current assets should be higher than total current liabilities."),
+                verdict: match (balance.current_assets, balance.current_liabilities) {
+                    (Some(assets), Some(liabilities)) if assets > liabilities => Some(format!(
+                        "Current assets of {} are higher than current liabilities of {}.", amount(Some(assets)), amount(Some(liabilities))
+                    )),
+                    (Some(assets), Some(liabilities)) => Some(format!(
+                        "Current assets of {} are not higher than current liabilities of {}.", amount(Some(assets)), amount(Some(liabilities))
+                    )),
+                    _ => None
+                },
+            },
+            "equity" => Evaluation {
+                value: fiscal_year(amount(latest.equity)),
+                computation: Some(format!(
+                    "total_assets - total_liabilities = {} - {} = {}",
+                    amount(latest.assets), amount(latest.liabilities),
+                    amount(latest.assets.zip(latest.liabilities).map(|(assets, liabilities)| assets - liabilities))
+                )),
+                range: Some("The closer the equity is to the market price, the safer the investment."),
+                verdict: divide(latest.equity, stored(stock, "market_cap"))
+                    .map(|share| format!("Equity is {:.2}% of the market cap of {}.", share * 100.0, stock.market_cap)),
+            },
+            "market_value" => Evaluation {
+                value: stock.market_cap.clone(),
+                computation: match (price, balance.shares_outstanding) {
+                    (Some(price), Some(shares)) => Some(format!(
+                        "current_price * shares_outstanding = {:.2} * {} = {}", price, amount(Some(shares)), amount(Some(price * shares))
+                    )),
+                    _ => None
+                },
+                range: None,
+                verdict: None,
+            },
+            "cash_from_operating_activities" => Evaluation {
+                value: fiscal_year(amount(latest.cash_from_operating_activities)),
+                computation: None,
+                range: Some("This number should be positive and show a steady growth."),
+                verdict: latest.cash_from_operating_activities.map(|cash| {
+                    let sign = if cash > 0.0 { "Positive" } else { "Not positive" };
+                    match trend(Some(cash), prior.as_ref().and_then(|prior| prior.cash_from_operating_activities), &prior_period) {
+                        Some(trend) => format!("{} and {}.", sign, trend),
+                        None => format!("{}.", sign)
+                    }
+                }),
+            },
+            "cash_from_investing_activities" | "cash_from_financing_activities" => {
+                let cash = match term {
+                    "cash_from_investing_activities" => latest.cash_from_investing_activities,
+                    _ => latest.cash_from_financing_activities
+                };
+
+                Evaluation {
+                    value: fiscal_year(amount(cash)),
+                    computation: None,
+                    range: Some("This number should be negative."),
+                    verdict: cash.map(|cash| match cash < 0.0 {
+                        true => "Negative.".to_string(),
+                        false => "Not negative.".to_string()
+                    }),
+                }
+            },
+            _ => return None
+        };
+
+        Some(evaluation)
+    }
+
+    pub fn print_evaluation(term: &str, stock: &Stock, rows: &[StatementRow]) {
+        println!();
+
+        let evaluation = match evaluate(term, stock, rows) {
+            Some(evaluation) => evaluation,
+            None => {
+                println!("'{}' isn't linked to values of a stock.", term);
+                return
+            }
+        };
+
+        println!("Stock: {}", stock.name.to_uppercase());
+        println!("  - Value: {}", evaluation.value);
+        if let Some(computation) = evaluation.computation {
+            println!("  - Computed as: {}", computation);
+        }
+        println!("  - Good range: {}", evaluation.range.unwrap_or("The explanation doesn't describe one."));
+        if let Some(verdict) = evaluation.verdict {
+            println!("  - Verdict: {}", verdict);
+        } else if evaluation.range.is_some() {
+            println!("  - Verdict: Values are missing to judge it.");
+        }
+
+        if rows.is_empty() && STATEMENT_TERMS.contains(&term) {
+            println!();
+            println!("Some values are computed from the annual statements. Run 'financials {} --statement balance' to fetch them.", stock.name);
+        }
+    }
+}
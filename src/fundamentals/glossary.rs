@@ -0,0 +1,221 @@
+pub mod glossary {
+    use std::{fs::File, path::Path};
+
+    use serde::Deserialize;
+
+    use crate::errors::error_handler::error_handler::GlossaryError;
+    use crate::output::locale::locale::Locale;
+
+    const TERMS: &str = include_str!("glossary.yml");
+    const TERMS_DE: &str = include_str!("glossary.de.yml");
+    const USER_TERMS: &str = "config/glossary.yml";
+
+    #[derive(Debug, Clone, Deserialize)]
+    pub struct Term {
+        pub id: String,
+        #[serde(default)]
+        pub aliases: Vec<String>,
+        #[serde(default)]
+        pub formula: Option<String>,
+        #[serde(default)]
+        pub related: Vec<String>,
+        pub body: String,
+    }
+
+    impl Term {
+        fn names(&self) -> impl Iterator<Item = &String> {
+            std::iter::once(&self.id).chain(self.aliases.iter())
+        }
+    }
+
+    pub enum Lookup<'a> {
+        /// The id or an alias of the term.
+        Exact(&'a Term),
+        /// The only term starting with the name, or the closest one to a misspelt name.
+        Guess(&'a Term),
+        Ambiguous(Vec<&'a Term>),
+        NotFound,
+    }
+
+    /// Terms of config/glossary.yml, none if the file doesn't exist.
+    pub fn user_terms() -> Result<Vec<Term>, GlossaryError> {
+        if !Path::new(USER_TERMS).exists() {
+            return Ok(Vec::new())
+        }
+
+        let file = File::open(USER_TERMS)?;
+
+        Ok(serde_yaml::from_reader(file)?)
+    }
+
+    /// Replaces terms of the same id and adds the others.
+    fn merge(terms: &mut Vec<Term>, replacements: Vec<Term>) {
+        for term in replacements {
+            match terms.iter_mut().find(|existing| existing.id == term.id) {
+                Some(existing) => *existing = term,
+                None => terms.push(term)
+            }
+        }
+    }
+
+    /// Built-in terms in the locale, falling back to English for terms which aren't
+    /// translated, together with the ones of config/glossary.yml, which replace
+    /// built-in terms of the same id.
+    pub fn terms(locale: Locale) -> Vec<Term> {
+        let mut terms: Vec<Term> = serde_yaml::from_str(TERMS).expect("Built-in glossary is not valid.");
+
+        if locale == Locale::De {
+            merge(&mut terms, serde_yaml::from_str(TERMS_DE).expect("Built-in German glossary is not valid."));
+        }
+
+        match user_terms() {
+            Ok(user_terms) => merge(&mut terms, user_terms),
+            Err(e) => println!("{}\n", e)
+        }
+
+        terms
+    }
+
+    /// Levenshtein distance between two names.
+    fn distance(a: &str, b: &str) -> usize {
+        let b: Vec<char> = b.chars().collect();
+        let mut row: Vec<usize> = (0..=b.len()).collect();
+
+        for (i, a) in a.chars().enumerate() {
+            let mut diagonal = row[0];
+            row[0] = i + 1;
+
+            for (j, b) in b.iter().enumerate() {
+                let above = row[j + 1];
+                row[j + 1] = if a == *b { diagonal } else { 1 + diagonal.min(row[j]).min(above) };
+                diagonal = above;
+            }
+        }
+
+        row[b.len()]
+    }
+
+    pub fn find<'a>(terms: &'a [Term], name: &str) -> Lookup<'a> {
+        let name = name.trim().to_lowercase().replace(['-', ' '], "_");
+
+        if name.is_empty() {
+            return Lookup::NotFound
+        }
+
+        if let Some(term) = terms.iter().find(|term| term.names().any(|term_name| *term_name == name)) {
+            return Lookup::Exact(term)
+        }
+
+        let prefixed: Vec<&Term> = terms.iter()
+            .filter(|term| term.names().any(|term_name| term_name.starts_with(&name)))
+            .collect();
+
+        match prefixed.len() {
+            0 => (),
+            1 => return Lookup::Guess(prefixed[0]),
+            _ => return Lookup::Ambiguous(prefixed)
+        }
+
+        // Names of three or four letters allow one typo, longer ones two.
+        let allowed = match name.chars().count() {
+            0..=2 => 0,
+            3 | 4 => 1,
+            _ => 2
+        };
+        let distances: Vec<(&Term, usize)> = terms.iter()
+            .filter_map(|term| term.names().map(|term_name| distance(&name, term_name)).min().map(|distance| (term, distance)))
+            .filter(|(_, distance)| *distance <= allowed)
+            .collect();
+        let closest: Vec<&Term> = match distances.iter().map(|(_, distance)| *distance).min() {
+            Some(min) => distances.iter().filter(|(_, distance)| *distance == min).map(|(term, _)| *term).collect(),
+            None => Vec::new()
+        };
+
+        match closest.len() {
+            0 => Lookup::NotFound,
+            1 => Lookup::Guess(closest[0]),
+            _ => Lookup::Ambiguous(closest)
+        }
+    }
+
+    pub fn print_term(term: &Term, locale: Locale) {
+        println!("=============");
+        println!("{}", term.body.trim_end());
+
+        if let Some(formula) = &term.formula {
+            println!();
+            println!("{}:\n", locale.label("Formula"));
+            println!("- {}", formula);
+        }
+
+        if !term.related.is_empty() {
+            println!();
+            println!("{}: {}", locale.label("Related"), term.related.join(", "));
+        }
+        println!("=============");
+    }
+
+    /// Prints the term matching the name and returns its id.
+    pub fn print_expl(expl: &str, locale: Locale) -> Option<String> {
+        let terms = terms(locale);
+
+        match find(&terms, expl) {
+            Lookup::Exact(term) => {
+                print_term(term, locale);
+                Some(term.id.clone())
+            },
+            Lookup::Guess(term) => {
+                println!("{}\n", locale.fill("No term '{}' was found, showing '{}'.", &[expl, &term.id]));
+                print_term(term, locale);
+                Some(term.id.clone())
+            },
+            Lookup::Ambiguous(candidates) => {
+                println!("{}", locale.fill("'{}' matches several terms:", &[expl]));
+                for term in candidates {
+                    println!("  - {}", term.id)
+                }
+                None
+            },
+            Lookup::NotFound => {
+                println!("{}", locale.label("No term was found. The following are supported:"));
+                for term in &terms {
+                    println!("  - {}", term.id)
+                }
+                None
+            }
+        }
+    }
+
+    /// Lists the terms whose id, aliases, formula or body contain the query,
+    /// with the first line of the body that contains it.
+    pub fn print_search(query: &str, locale: Locale) {
+        let query = query.trim().to_lowercase();
+        let terms = terms(locale);
+
+        let matches: Vec<(&Term, Option<&str>)> = terms.iter()
+            .filter_map(|term| {
+                let line = term.body.lines().find(|line| line.to_lowercase().contains(&query));
+                let named = term.names().any(|name| name.contains(&query.replace(' ', "_")));
+                let in_formula = term.formula.as_ref().is_some_and(|formula| formula.to_lowercase().contains(&query));
+
+                match named || in_formula || line.is_some() {
+                    true => Some((term, line)),
+                    false => None
+                }
+            })
+            .collect();
+
+        if matches.is_empty() {
+            println!("{}", locale.fill("No term contains '{}'.", &[&query]));
+            return
+        }
+
+        println!("{}", locale.fill("Terms containing '{}':", &[&query]));
+        for (term, line) in matches {
+            match line {
+                Some(line) => println!("  - {}: {}", term.id, line.trim()),
+                None => println!("  - {}", term.id)
+            }
+        }
+    }
+}
@@ -1,8 +1,9 @@
+#![allow(clippy::module_inception)]
+
 use chronoutil::{shift_months, shift_years};
 use tokio_postgres::{Error, NoTls};
 use clap::Parser;
 use chrono::{prelude::*, Duration};
-use serde_yaml;
 use std::{
     fs::{OpenOptions, File}, 
     io::{BufWriter, Write}, 
@@ -14,6 +15,11 @@ mod file;
 mod errors;
 mod scraper;
 mod fundamentals;
+mod analytics;
+mod portfolio;
+mod output;
+mod valuation;
+mod statements;
 
 use database::{cmd::cmd as database_cmd, database::database::read_database_url};
 use file::cmd::cmd as file_cmd;
@@ -30,23 +36,237 @@ pub struct Opt {
 
 #[derive(Debug, Parser)]
 pub enum Command {
-    Add { stock_name: String },
-    List {},
+    Add {
+        stock_name: String,
+        #[clap(long)]
+        list: Option<String>,
+    },
+    List {
+        #[clap(subcommand)]
+        action: Option<ListCommand>,
+        #[clap(long)]
+        list: Option<String>,
+        #[clap(long, value_delimiter = ',')]
+        columns: Vec<String>,
+        #[clap(long)]
+        sort: Option<String>,
+        #[clap(long)]
+        desc: bool,
+        #[clap(long)]
+        limit: Option<usize>,
+    },
     Search { stock_name: String },
-    Drop { stock_name: String },
+    Drop {
+        stock_name: String,
+        #[clap(long)]
+        list: Option<String>,
+    },
     Update { stock_name: String },
-    UpdateAll {},
-    History { stock_name: String, date: String },
+    UpdateAll {
+        #[clap(long)]
+        list: Option<String>,
+    },
+    Watch {
+        #[clap(long, default_value = "15m")]
+        every: String,
+        #[clap(long)]
+        market_hours_only: bool,
+    },
+    History {
+        stock_name: String,
+        date: String,
+        #[clap(long, default_value = "1d")]
+        interval: String,
+    },
+    Stats {
+        stock_name: String,
+        from: String,
+        to: Option<String>,
+        #[clap(long, default_value = "0")]
+        risk_free: f64,
+        #[clap(long)]
+        json: bool,
+    },
+    Compare {
+        #[clap(required = true)]
+        stock_names: Vec<String>,
+        #[clap(long, default_value = "1.year.ago")]
+        since: String,
+        #[clap(long)]
+        normalise: bool,
+    },
+    Peers {
+        #[clap(required = true)]
+        stock_names: Vec<String>,
+    },
+    #[clap(alias = "alerts")]
+    Alert {
+        #[clap(subcommand)]
+        action: AlertCommand,
+    },
+    Buy {
+        stock_name: String,
+        quantity: f64,
+        price: f64,
+        #[clap(long)]
+        date: Option<String>,
+        #[clap(long, default_value = "0")]
+        fee: f64,
+    },
+    Sell {
+        stock_name: String,
+        quantity: f64,
+        price: f64,
+        #[clap(long)]
+        date: Option<String>,
+        #[clap(long, default_value = "0")]
+        fee: f64,
+    },
+    Portfolio {
+        #[clap(subcommand)]
+        view: Option<PortfolioCommand>,
+    },
+    ImportTransactions {
+        csv: String,
+        #[clap(long, default_value = "generic")]
+        format: String,
+        #[clap(long)]
+        dry_run: bool,
+    },
+    Dividends {
+        #[clap(long)]
+        forecast: bool,
+    },
+    Gains {
+        #[clap(long)]
+        year: Option<i32>,
+        #[clap(long, default_value = "fifo")]
+        method: String,
+    },
+    Benchmark {
+        #[clap(long, default_value = "1.year.ago")]
+        since: String,
+        #[clap(long)]
+        to: Option<String>,
+        #[clap(long)]
+        list: Option<String>,
+    },
+    SetBenchmark { symbol: String },
+    SetLocale { locale: String },
+    Screen { expression: String },
+    Dcf {
+        stock_name: String,
+        #[clap(long)]
+        fcf: Option<String>,
+        #[clap(long)]
+        shares: Option<String>,
+        #[clap(long, default_value = "5")]
+        growth: f64,
+        #[clap(long, default_value = "5")]
+        years: u32,
+        #[clap(long, default_value = "2.5")]
+        terminal_growth: f64,
+        #[clap(long, default_value = "9")]
+        discount_rate: f64,
+    },
+    Financials {
+        stock_name: String,
+        #[clap(long, default_value = "income")]
+        statement: String,
+        #[clap(long)]
+        quarterly: bool,
+        #[clap(long)]
+        update: bool,
+    },
+    Score {
+        stock_name: String,
+        #[clap(long)]
+        update: bool,
+    },
+    Value {
+        stock_name: String,
+        #[clap(long)]
+        growth: Option<f64>,
+        #[clap(long, default_value = "4.4")]
+        bond_yield: f64,
+    },
+    Target {
+        #[clap(subcommand)]
+        action: TargetCommand,
+    },
+    Rebalance {
+        #[clap(long, default_value = "0")]
+        cash: f64,
+        #[clap(long, default_value = "0")]
+        min_trade: f64,
+        #[clap(long)]
+        whole_shares: bool,
+    },
     Info {
         #[clap(default_value = "")]
         explanation: String,
+        stock_name: Option<String>,
+        #[clap(long)]
+        search: Option<String>,
     },
     Init {},
     ShowDB {},
     SetDB { url: String },
+    Migrate {},
     Mode {},
 }
 
+#[derive(Debug, Parser)]
+pub enum ListCommand {
+    Create { name: String },
+    Delete { name: String },
+    Show {},
+}
+
+#[derive(Debug, Parser)]
+pub enum AlertCommand {
+    Add {
+        stock_name: String,
+        #[clap(required = true, allow_hyphen_values = true)]
+        condition: Vec<String>,
+    },
+    Remove { id: i32 },
+    List {},
+    Check {},
+}
+
+#[derive(Debug, Parser)]
+pub enum TargetCommand {
+    Set {
+        name: String,
+        weight: f64,
+        #[clap(long)]
+        tag: bool,
+    },
+    Remove {
+        name: String,
+        #[clap(long)]
+        tag: bool,
+    },
+    Show {},
+}
+
+#[derive(Debug, Parser)]
+pub enum PortfolioCommand {
+    Returns {
+        #[clap(long)]
+        since: String,
+        #[clap(long)]
+        to: Option<String>,
+    },
+    Allocation {
+        #[clap(long, default_value = "sector")]
+        by: String,
+        #[clap(long, default_value = "40")]
+        warn_above: f64,
+    },
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Error> {
     let opt = Opt::parse();
@@ -0,0 +1,3 @@
+pub mod table;
+pub mod listing;
+pub mod locale;
\ No newline at end of file
@@ -0,0 +1,85 @@
+pub mod listing {
+    use std::cmp::Ordering;
+
+    use crate::database::queries::queries::{Stock, NUMERIC_FIELDS, TEXT_FIELDS};
+    use crate::output::table::table::print_table;
+
+    /// Short names which can be used instead of the field names.
+    const ALIASES: &[(&str, &str)] = &[
+        ("symbol", "name"),
+        ("price", "current_price"),
+        ("change", "change_since"),
+        ("eps", "eps_ttm"),
+        ("pe", "pe_ratio"),
+        ("peg", "peg_ratio"),
+        ("debt_equity", "total_debt_equity"),
+        ("roe", "return_on_equity"),
+        ("roa", "return_on_assets"),
+    ];
+
+    pub fn resolve_column(column: &str) -> Result<&'static str, String> {
+        let column = column.trim().to_lowercase();
+
+        ALIASES.iter()
+            .find(|(alias, _)| *alias == column)
+            .map(|(_, field)| *field)
+            .or_else(|| NUMERIC_FIELDS.iter().map(|(field, _)| *field).find(|field| *field == column))
+            .or_else(|| TEXT_FIELDS.iter().copied().find(|field| *field == column))
+            .ok_or_else(|| format!(
+                "Unknown column '{}'. Available columns: {}, {}",
+                column,
+                TEXT_FIELDS.join(", "),
+                NUMERIC_FIELDS.iter().map(|(field, _)| *field).collect::<Vec<&str>>().join(", ")
+            ))
+    }
+
+    /// Numeric fields are compared by their value, stocks without a value come last.
+    fn compare(a: &Stock, b: &Stock, field: &str, descending: bool) -> Ordering {
+        let ordering = match (a.field(field), b.field(field)) {
+            (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+            (Some(_), None) => return Ordering::Less,
+            (None, Some(_)) => return Ordering::Greater,
+            (None, None) => a.display(field).cmp(&b.display(field)),
+        };
+
+        if descending { ordering.reverse() } else { ordering }
+    }
+
+    pub fn print_stocks(
+        mut stocks: Vec<Stock>, 
+        columns: Vec<String>, 
+        sort: Option<String>, 
+        descending: bool, 
+        limit: Option<usize>
+    ) {
+        let mut fields = vec!["name"];
+        for column in &columns {
+            match resolve_column(column) {
+                Ok(field) if !fields.contains(&field) => fields.push(field),
+                Ok(_) => {},
+                Err(e) => return println!("{}", e)
+            }
+        }
+
+        let sort = match sort.map(|sort| resolve_column(&sort)) {
+            Some(Ok(field)) => field,
+            Some(Err(e)) => return println!("{}", e),
+            None => "name"
+        };
+
+        stocks.sort_by(|a, b| compare(a, b, sort, descending));
+
+        if let Some(limit) = limit {
+            stocks.truncate(limit);
+        }
+
+        let headers: Vec<&str> = fields.iter()
+            .map(|field| if *field == "name" { "symbol" } else { field })
+            .collect();
+        let rows: Vec<Vec<String>> = stocks.iter()
+            .map(|stock| fields.iter().map(|field| stock.display(field).unwrap_or_default()).collect())
+            .collect();
+
+        print_table(&headers, &rows);
+    }
+}
@@ -0,0 +1,177 @@
+pub mod locale {
+    use std::{fs::OpenOptions, io::{BufWriter, Write}};
+
+    use crate::errors::error_handler::error_handler::LocaleError;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum Locale {
+        #[default]
+        En,
+        De,
+    }
+
+    /// German labels by their English text. Labels which aren't listed are printed in English.
+    const LABELS_DE: &[(&str, &str)] = &[
+        ("Stock", "Aktie"),
+        ("Stock was not found.", "Aktie wurde nicht gefunden."),
+        ("Current Price", "Aktueller Kurs"),
+        ("Market Cap", "Marktkapitalisierung"),
+        ("EPS (ttm)", "Gewinn je Aktie (ttm)"),
+        ("P/E", "KGV"),
+        ("PEG ratio", "PEG-Verhältnis"),
+        ("Price/Book (mrq)", "Kurs-Buchwert-Verhältnis (mrq)"),
+        ("Book Value per Share (mrq)", "Buchwert je Aktie (mrq)"),
+        ("Revenue (ttm)", "Umsatz (ttm)"),
+        ("Gross Profit (ttm)", "Bruttogewinn (ttm)"),
+        ("Total Cash (mrq)", "Barmittel (mrq)"),
+        ("Total Debt (mrq)", "Gesamtverschuldung (mrq)"),
+        ("Total Debt/Equity", "Verschuldungsgrad"),
+        ("Return on Equity (ttm)", "Eigenkapitalrendite (ttm)"),
+        ("Return on Assets (ttm)", "Gesamtkapitalrendite (ttm)"),
+        ("Sector", "Sektor"),
+        ("Industry", "Branche"),
+        ("Country", "Land"),
+        ("Currency", "Währung"),
+        ("Price since last update", "Kurs seit der letzten Aktualisierung"),
+        ("Date", "Datum"),
+        ("Times in", "Zeiten in"),
+        ("Time", "Zeit"),
+        ("Open", "Eröffnung"),
+        ("High", "Hoch"),
+        ("Low", "Tief"),
+        ("Close", "Schluss"),
+        ("Volume", "Volumen"),
+        ("Price", "Kurs"),
+        ("Increase until today", "Anstieg bis heute"),
+        ("Decrease until today", "Rückgang bis heute"),
+        ("Please take another day.", "Bitte wähle einen anderen Tag."),
+        ("It's a Saturday so we'll take Monday.", "Es ist ein Samstag, daher wird der Montag genommen."),
+        ("It's a Sunday so we'll take Monday.", "Es ist ein Sonntag, daher wird der Montag genommen."),
+        ("The entered date lies in the future. Please provide a date from the past.", "Das Datum liegt in der Zukunft. Bitte gib ein Datum aus der Vergangenheit an."),
+        ("Date is a holiday or a day in which the stock exchange was closed.", "Das Datum ist ein Feiertag oder ein Tag, an dem die Börse geschlossen war."),
+        ("Monday", "Montag"),
+        ("Tuesday", "Dienstag"),
+        ("Wednesday", "Mittwoch"),
+        ("Thursday", "Donnerstag"),
+        ("Friday", "Freitag"),
+        ("Saturday", "Samstag"),
+        ("Sunday", "Sonntag"),
+        ("Formula", "Formel"),
+        ("Related", "Verwandte Begriffe"),
+        ("No term '{}' was found, showing '{}'.", "Der Begriff '{}' wurde nicht gefunden, es wird '{}' angezeigt."),
+        ("'{}' matches several terms:", "'{}' passt zu mehreren Begriffen:"),
+        ("No term was found. The following are supported:", "Kein Begriff wurde gefunden. Folgende werden unterstützt:"),
+        ("No term contains '{}'.", "Kein Begriff enthält '{}'."),
+        ("Terms containing '{}':", "Begriffe, die '{}' enthalten:"),
+        ("Term", "Begriff"),
+        ("Computed from the annual financial statements (run 'info TERM' for an explanation):", "Berechnet aus den jährlichen Finanzberichten (mit 'info TERM' wird ein Begriff erklärt):"),
+        ("No financial statements are saved. Run 'financials SYMBOL --statement balance' (and income, cashflow) to compute ratios.", "Es sind keine Finanzberichte gespeichert. Mit 'financials SYMBOL --statement balance' (sowie income und cashflow) werden sie für die Kennzahlen geladen."),
+        ("Interval {} is not supported. Use 1m, 5m, 15m, 1h or 1d.", "Das Intervall {} wird nicht unterstützt. Verwende 1m, 5m, 15m, 1h oder 1d."),
+        ("Prices in {} intervals are only available for the last {} days.", "Kurse in {}-Intervallen gibt es nur für die letzten {} Tage."),
+    ];
+
+    impl Locale {
+        pub fn parse(value: &str) -> Option<Locale> {
+            match value.to_lowercase().as_str() {
+                "en" | "english" => Some(Locale::En),
+                "de" | "german" | "deutsch" => Some(Locale::De),
+                _ => None
+            }
+        }
+
+        pub fn as_str(&self) -> &'static str {
+            match self {
+                Locale::En => "en",
+                Locale::De => "de",
+            }
+        }
+
+        /// The label in this locale. Placeholders ('{}') are filled with `fill`.
+        pub fn label<'a>(&self, label: &'a str) -> &'a str {
+            match self {
+                Locale::En => label,
+                Locale::De => LABELS_DE.iter()
+                    .find(|(english, _)| *english == label)
+                    .map(|(_, german)| *german)
+                    .unwrap_or(label),
+            }
+        }
+
+        /// The label with its placeholders replaced by the values in order.
+        pub fn fill(&self, label: &str, values: &[&str]) -> String {
+            values.iter().fold(self.label(label).to_string(), |label, value| label.replacen("{}", value, 1))
+        }
+
+        /// Formats a number with the decimal and thousands separator of the locale,
+        /// e.g. 1234567.891 as 1,234,567.89 in English and 1.234.567,89 in German.
+        pub fn number(&self, value: f64, decimals: usize) -> String {
+            let formatted = format!("{:.*}", decimals, value.abs());
+            let (integer, fraction) = formatted.split_once('.').unwrap_or((&formatted, ""));
+            let (thousands, decimal) = match self {
+                Locale::En => (',', '.'),
+                Locale::De => ('.', ','),
+            };
+
+            let mut number = String::new();
+            if value < 0.0 && formatted.chars().any(|digit| digit.is_ascii_digit() && digit != '0') {
+                number.push('-');
+            }
+            for (i, digit) in integer.chars().enumerate() {
+                if i > 0 && (integer.len() - i) % 3 == 0 {
+                    number.push(thousands);
+                }
+                number.push(digit);
+            }
+            if !fraction.is_empty() {
+                number.push(decimal);
+                number.push_str(fraction);
+            }
+
+            number
+        }
+
+        /// Formats a number saved as text like "3.1T", "-1.2%" or "200.5", keeping its
+        /// decimals, sign and suffix. Text which isn't a number is returned unchanged.
+        pub fn value(&self, text: &str) -> String {
+            let text = text.trim();
+            let end = text.rfind(|character: char| character.is_ascii_digit()).map(|end| end + 1).unwrap_or(0);
+            let (number, suffix) = text.split_at(end);
+            let decimals = number.split_once('.').map(|(_, fraction)| fraction.len()).unwrap_or(0);
+
+            match number.parse::<f64>() {
+                Ok(value) => {
+                    let sign = if number.starts_with('+') { "+" } else { "" };
+                    format!("{}{}{}", sign, self.number(value, decimals), suffix)
+                },
+                Err(_) => text.to_string()
+            }
+        }
+    }
+
+    /// The locale of config/locale.yml.
+    pub fn read_locale() -> Result<Locale, LocaleError> {
+        let file = std::fs::File::open("config/locale.yml")?;
+        let locale: serde_yaml::Mapping = serde_yaml::from_reader(file)?;
+        let value = locale.get("locale").and_then(|value| value.as_str()).unwrap_or("");
+
+        Locale::parse(value).ok_or(LocaleError::UnknownLocale { locale: value.to_string() })
+    }
+
+    /// The locale of config/locale.yml, English if none is set.
+    pub fn current_locale() -> Locale {
+        read_locale().unwrap_or_default()
+    }
+
+    pub fn set_locale(locale: Locale) -> Result<(), LocaleError> {
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open("config/locale.yml")?;
+
+        let mut writer = BufWriter::new(file);
+        write!(writer, "locale: \"{}\"", locale.as_str())?;
+
+        Ok(())
+    }
+}
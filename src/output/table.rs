@@ -0,0 +1,40 @@
+pub mod table {
+    /// Prints rows as aligned columns. The first column is aligned to the left, 
+    /// all others to the right so numbers line up.
+    pub fn print_table(headers: &[&str], rows: &[Vec<String>]) {
+        let mut widths: Vec<usize> = headers.iter().map(|header| header.chars().count()).collect();
+
+        for row in rows {
+            for (i, cell) in row.iter().enumerate() {
+                if i < widths.len() {
+                    widths[i] = widths[i].max(cell.chars().count());
+                }
+            }
+        }
+
+        let header_cells: Vec<String> = headers.iter().map(|header| header.to_string()).collect();
+        println!("{}", format_row(&header_cells, &widths));
+        println!("{}", widths.iter().map(|width| "-".repeat(*width)).collect::<Vec<String>>().join("  "));
+
+        for row in rows {
+            println!("{}", format_row(row, &widths));
+        }
+    }
+
+    fn format_row(row: &[String], widths: &[usize]) -> String {
+        row.iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(i, (cell, width))| {
+                if i == 0 {
+                    format!("{:<width$}", cell, width = width)
+                } else {
+                    format!("{:>width$}", cell, width = width)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    }
+}
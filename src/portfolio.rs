@@ -0,0 +1,8 @@
+pub mod transactions;
+pub mod positions;
+pub mod gains;
+pub mod dividends;
+pub mod import;
+pub mod returns;
+pub mod rebalance;
+pub mod allocation;
\ No newline at end of file
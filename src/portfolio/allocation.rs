@@ -0,0 +1,136 @@
+pub mod allocation {
+    use std::collections::HashMap;
+
+    use crate::output::table::table::print_table;
+    use crate::portfolio::positions::positions::positions;
+    use crate::portfolio::transactions::transactions::Transaction;
+
+    #[derive(Debug, Clone, Default)]
+    pub struct Classification {
+        pub sector: String,
+        pub industry: String,
+        pub country: String,
+        pub currency: String,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Dimension {
+        Sector,
+        Industry,
+        Country,
+        Currency,
+    }
+
+    impl Dimension {
+        pub fn parse(value: &str) -> Option<Dimension> {
+            match value.to_lowercase().as_str() {
+                "sector" => Some(Dimension::Sector),
+                "industry" => Some(Dimension::Industry),
+                "country" => Some(Dimension::Country),
+                "currency" => Some(Dimension::Currency),
+                _ => None
+            }
+        }
+
+        pub fn as_str(&self) -> &'static str {
+            match self {
+                Dimension::Sector => "Sector",
+                Dimension::Industry => "Industry",
+                Dimension::Country => "Country",
+                Dimension::Currency => "Currency",
+            }
+        }
+    }
+
+    impl Classification {
+        pub fn get(&self, dimension: Dimension) -> &str {
+            match dimension {
+                Dimension::Sector => &self.sector,
+                Dimension::Industry => &self.industry,
+                Dimension::Country => &self.country,
+                Dimension::Currency => &self.currency,
+            }
+        }
+    }
+
+    pub struct Group {
+        pub name: String,
+        pub value: f64,
+        pub symbols: Vec<String>,
+    }
+
+    /// Market value of all held positions grouped by the given dimension, largest group first.
+    pub fn allocation(
+        transactions: &[Transaction],
+        prices: &HashMap<String, f64>,
+        classifications: &HashMap<String, Classification>,
+        dimension: Dimension,
+    ) -> Vec<Group> {
+        let mut groups: Vec<Group> = Vec::new();
+
+        for position in positions(transactions) {
+            let value = match prices.get(&position.symbol) {
+                Some(price) => price * position.quantity,
+                None => continue
+            };
+
+            let name = classifications.get(&position.symbol)
+                .map(|classification| classification.get(dimension).to_string())
+                .unwrap_or_else(|| "Unknown".to_string());
+
+            match groups.iter_mut().find(|group| group.name == name) {
+                Some(group) => {
+                    group.value += value;
+                    group.symbols.push(position.symbol.to_uppercase());
+                },
+                None => groups.push(Group { name, value, symbols: vec![position.symbol.to_uppercase()] })
+            }
+        }
+
+        groups.sort_by(|a, b| b.value.total_cmp(&a.value));
+        groups
+    }
+
+    pub fn print_allocation(
+        transactions: &[Transaction],
+        prices: &HashMap<String, f64>,
+        classifications: &HashMap<String, Classification>,
+        dimension: Dimension,
+        warn_above: f64,
+    ) {
+        let groups = allocation(transactions, prices, classifications, dimension);
+        let total: f64 = groups.iter().map(|group| group.value).sum();
+
+        if groups.is_empty() || total <= 0.0 {
+            return println!("There are no positions with a current price yet.")
+        }
+
+        let rows: Vec<Vec<String>> = groups.iter()
+            .map(|group| vec![
+                group.name.clone(),
+                format!("{:.2}", group.value),
+                format!("{:.2}%", group.value / total * 100.0),
+                group.symbols.join(" "),
+            ])
+            .collect();
+
+        print_table(&[dimension.as_str(), "Value", "Weight", "Holdings"], &rows);
+
+        println!();
+
+        for group in &groups {
+            let weight = group.value / total * 100.0;
+
+            if weight > warn_above {
+                println!(
+                    "Warning: {:.2}% of the portfolio is in {} {}, which is more than {:.0}%.", 
+                    weight, dimension.as_str().to_lowercase(), group.name, warn_above
+                );
+            }
+        }
+
+        if groups.iter().any(|group| group.name == "Unknown") {
+            println!("Some stocks have no {}. Run 'update-all' to fetch it.", dimension.as_str().to_lowercase());
+        }
+    }
+}
@@ -0,0 +1,153 @@
+pub mod dividends {
+    use std::collections::BTreeMap;
+
+    use chrono::{Datelike, Duration, Local, NaiveDate};
+    use chronoutil::shift_years;
+
+    use crate::output::table::table::print_table;
+    use crate::portfolio::transactions::transactions::{Transaction, quantity_held};
+    use crate::scraper::financial_data::get_financial_data::dividend_history;
+
+    #[derive(Debug, Clone)]
+    pub struct Payment {
+        pub symbol: String,
+        pub ex_date: NaiveDate,
+        pub quantity: f64,
+        pub per_share: f64,
+    }
+
+    impl Payment {
+        pub fn amount(&self) -> f64 {
+            self.quantity * self.per_share
+        }
+    }
+
+    fn symbols(transactions: &[Transaction]) -> Vec<String> {
+        let mut symbols: Vec<String> = transactions.iter().map(|transaction| transaction.symbol.clone()).collect();
+        symbols.sort();
+        symbols.dedup();
+        symbols
+    }
+
+    /// Dividends received for every ex-date on which shares were held. Only shares 
+    /// bought before the ex-date are entitled to the dividend.
+    pub async fn received(transactions: &[Transaction]) -> Vec<Payment> {
+        let today = Local::now().date_naive();
+        let mut payments = Vec::new();
+
+        for symbol in symbols(transactions) {
+            let first_buy = match transactions.iter().filter(|t| t.symbol == symbol).map(|t| t.date).min() {
+                Some(date) => date,
+                None => continue
+            };
+
+            let dividends = match dividend_history(&symbol, first_buy, today).await {
+                Ok(dividends) => dividends,
+                Err(e) => {
+                    println!("{}", e);
+                    continue
+                }
+            };
+
+            for (ex_date, per_share) in dividends {
+                let held: Vec<Transaction> = transactions.iter()
+                    .filter(|transaction| transaction.date < ex_date)
+                    .cloned()
+                    .collect();
+                let quantity = quantity_held(&held, &symbol);
+
+                if quantity > f64::EPSILON {
+                    payments.push(Payment { symbol: symbol.clone(), ex_date, quantity, per_share });
+                }
+            }
+        }
+
+        payments
+    }
+
+    /// Expected dividends of the next 12 months: every payout of the last 12 months
+    /// is repeated a year later for the quantity held today.
+    pub async fn forecast(transactions: &[Transaction]) -> Vec<Payment> {
+        let today = Local::now().date_naive();
+        let year_ago = shift_years(today, -1);
+        let mut payments = Vec::new();
+
+        for symbol in symbols(transactions) {
+            let quantity = quantity_held(transactions, &symbol);
+
+            if quantity <= f64::EPSILON {
+                continue
+            }
+
+            match dividend_history(&symbol, year_ago + Duration::days(1), today).await {
+                Ok(dividends) => payments.extend(dividends.into_iter().map(|(ex_date, per_share)| Payment {
+                    symbol: symbol.clone(),
+                    ex_date: shift_years(ex_date, 1),
+                    quantity,
+                    per_share,
+                })),
+                Err(e) => println!("{}", e)
+            }
+        }
+
+        payments
+    }
+
+    fn print_totals(title: &str, header: &str, totals: BTreeMap<String, f64>) {
+        let rows: Vec<Vec<String>> = totals.iter()
+            .map(|(key, amount)| vec![key.clone(), format!("{:.2}", amount)])
+            .collect();
+
+        println!("{}", title);
+        print_table(&[header, "Dividends"], &rows);
+    }
+
+    fn group_by<F: Fn(&Payment) -> String>(payments: &[Payment], key: F) -> BTreeMap<String, f64> {
+        let mut totals = BTreeMap::new();
+
+        for payment in payments {
+            *totals.entry(key(payment)).or_insert(0.0) += payment.amount();
+        }
+
+        totals
+    }
+
+    pub async fn print_dividends(transactions: &[Transaction], show_forecast: bool) {
+        if transactions.is_empty() {
+            return println!("There are no transactions. Run 'buy' to record one.")
+        }
+
+        let payments = if show_forecast {
+            forecast(transactions).await
+        } else {
+            received(transactions).await
+        };
+
+        if payments.is_empty() {
+            return println!("No dividends found for your positions.")
+        }
+
+        if show_forecast {
+            println!("Forecast for the next 12 months based on the payouts of the last 12 months.\n");
+        }
+
+        let rows: Vec<Vec<String>> = payments.iter()
+            .map(|payment| vec![
+                payment.symbol.to_uppercase(),
+                payment.ex_date.format("%d.%m.%Y").to_string(),
+                format!("{}", payment.quantity),
+                format!("{:.4}", payment.per_share),
+                format!("{:.2}", payment.amount()),
+            ])
+            .collect();
+
+        print_table(&["Symbol", "Ex-Date", "Quantity", "Per Share", "Amount"], &rows);
+        println!();
+
+        print_totals("Per position", "Symbol", group_by(&payments, |payment| payment.symbol.to_uppercase()));
+        println!();
+        print_totals("Per month", "Month", group_by(&payments, |payment| payment.ex_date.format("%Y-%m").to_string()));
+        println!();
+        print_totals("Per year", "Year", group_by(&payments, |payment| payment.ex_date.year().to_string()));
+    }
+}
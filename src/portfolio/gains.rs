@@ -0,0 +1,168 @@
+pub mod gains {
+    use chrono::{Datelike, NaiveDate};
+
+    use crate::portfolio::transactions::transactions::{Kind, Transaction};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Method {
+        Fifo,
+        Lifo,
+        Average,
+    }
+
+    impl Method {
+        pub fn parse(method: &str) -> Option<Method> {
+            match method.to_lowercase().as_str() {
+                "fifo" => Some(Method::Fifo),
+                "lifo" => Some(Method::Lifo),
+                "average" => Some(Method::Average),
+                _ => None
+            }
+        }
+    }
+
+    /// Shares of one buy which haven't been sold yet. The cost includes the fee of the buy.
+    #[derive(Debug, Clone)]
+    struct Lot {
+        date: NaiveDate,
+        quantity: f64,
+        cost_per_share: f64,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct RealisedGain {
+        pub symbol: String,
+        /// None if the shares come from several buys, which is always the case for the average method.
+        pub acquired: Option<NaiveDate>,
+        pub sold: NaiveDate,
+        pub quantity: f64,
+        pub proceeds: f64,
+        pub cost_basis: f64,
+        pub fees: f64,
+    }
+
+    impl RealisedGain {
+        pub fn gain(&self) -> f64 {
+            self.proceeds - self.cost_basis
+        }
+    }
+
+    /// Matches every sell against the buy lots of the same symbol. Proceeds are net of the 
+    /// sell fee, the cost basis includes the fees of the matched buys.
+    pub fn realised_gains(transactions: &[Transaction], method: Method) -> Vec<RealisedGain> {
+        let mut sorted = transactions.to_vec();
+        sorted.sort_by_key(|transaction| transaction.date);
+
+        let mut lots: Vec<(String, Vec<Lot>)> = Vec::new();
+        let mut gains = Vec::new();
+
+        for transaction in sorted {
+            let index = match lots.iter().position(|(symbol, _)| *symbol == transaction.symbol) {
+                Some(index) => index,
+                None => {
+                    lots.push((transaction.symbol.clone(), Vec::new()));
+                    lots.len() - 1
+                }
+            };
+            let symbol_lots = &mut lots[index].1;
+
+            match transaction.kind {
+                Kind::Buy => symbol_lots.push(Lot {
+                    date: transaction.date,
+                    quantity: transaction.quantity,
+                    cost_per_share: (transaction.quantity * transaction.price + transaction.fee) / transaction.quantity,
+                }),
+                Kind::Sell => gains.extend(match_sell(symbol_lots, &transaction, method)),
+            }
+        }
+
+        gains
+    }
+
+    fn match_sell(lots: &mut Vec<Lot>, sell: &Transaction, method: Method) -> Vec<RealisedGain> {
+        let fee_per_share = sell.fee / sell.quantity;
+        let gain = |acquired: Option<NaiveDate>, quantity: f64, cost_per_share: f64| RealisedGain {
+            symbol: sell.symbol.clone(),
+            acquired,
+            sold: sell.date,
+            quantity,
+            proceeds: quantity * (sell.price - fee_per_share),
+            cost_basis: quantity * cost_per_share,
+            fees: quantity * fee_per_share,
+        };
+
+        if method == Method::Average {
+            let held: f64 = lots.iter().map(|lot| lot.quantity).sum();
+            if held <= 0.0 {
+                return vec![]
+            }
+
+            let average_cost = lots.iter().map(|lot| lot.quantity * lot.cost_per_share).sum::<f64>() / held;
+            let quantity = sell.quantity.min(held);
+            let remaining = held - quantity;
+
+            lots.clear();
+            if remaining > f64::EPSILON {
+                lots.push(Lot { date: sell.date, quantity: remaining, cost_per_share: average_cost });
+            }
+
+            return vec![gain(None, quantity, average_cost)]
+        }
+
+        let mut gains = Vec::new();
+        let mut to_sell = sell.quantity;
+
+        while to_sell > f64::EPSILON && !lots.is_empty() {
+            let index = match method {
+                Method::Lifo => lots.len() - 1,
+                _ => 0
+            };
+
+            let lot = &mut lots[index];
+            let quantity = to_sell.min(lot.quantity);
+
+            gains.push(gain(Some(lot.date), quantity, lot.cost_per_share));
+
+            lot.quantity -= quantity;
+            to_sell -= quantity;
+
+            if lot.quantity <= f64::EPSILON {
+                lots.remove(index);
+            }
+        }
+
+        gains
+    }
+
+    pub fn print_gains(transactions: &[Transaction], year: i32, method: Method) {
+        let gains: Vec<RealisedGain> = realised_gains(transactions, method)
+            .into_iter()
+            .filter(|gain| gain.sold.year() == year)
+            .collect();
+
+        println!("symbol,acquired,sold,quantity,proceeds,cost_basis,sell_fees,gain");
+
+        for gain in &gains {
+            println!(
+                "{},{},{},{},{:.2},{:.2},{:.2},{:.2}",
+                gain.symbol.to_uppercase(),
+                gain.acquired.map(|date| date.format("%Y-%m-%d").to_string()).unwrap_or_else(|| "various".to_string()),
+                gain.sold.format("%Y-%m-%d"),
+                gain.quantity,
+                gain.proceeds,
+                gain.cost_basis,
+                gain.fees,
+                gain.gain(),
+            );
+        }
+
+        // The total goes to stderr, so a redirected report only contains the sold lots.
+        eprintln!(
+            "Total: proceeds {:.2}, cost basis {:.2}, sell fees {:.2}, gain {:.2}",
+            gains.iter().map(|gain| gain.proceeds).sum::<f64>(),
+            gains.iter().map(|gain| gain.cost_basis).sum::<f64>(),
+            gains.iter().map(|gain| gain.fees).sum::<f64>(),
+            gains.iter().map(|gain| gain.gain()).sum::<f64>(),
+        );
+    }
+}
@@ -0,0 +1,272 @@
+pub mod import {
+    use std::{collections::HashMap, fs::File};
+
+    use chrono::NaiveDate;
+    use serde::Deserialize;
+
+    use crate::errors::error_handler::error_handler::ImportError;
+    use crate::file::queries::queries::file_to_string;
+    use crate::output::table::table::print_table;
+    use crate::portfolio::transactions::transactions::{Kind, Transaction, validate};
+
+    #[derive(Deserialize, Debug)]
+    pub struct BrokerFormat {
+        #[serde(default = "default_delimiter")]
+        pub delimiter: char,
+        #[serde(default = "default_date_format")]
+        pub date_format: String,
+        #[serde(default)]
+        pub decimal_comma: bool,
+        pub columns: Columns,
+        #[serde(default = "default_buy")]
+        pub buy: String,
+        #[serde(default = "default_sell")]
+        pub sell: String,
+        #[serde(default)]
+        pub symbols: HashMap<String, String>,
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub struct Columns {
+        pub symbol: String,
+        pub kind: Option<String>,
+        pub quantity: String,
+        pub price: String,
+        pub fee: Option<String>,
+        pub date: String,
+    }
+
+    fn default_delimiter() -> char { ',' }
+    fn default_date_format() -> String { "%Y-%m-%d".to_string() }
+    fn default_buy() -> String { "buy".to_string() }
+    fn default_sell() -> String { "sell".to_string() }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Status {
+        New,
+        Duplicate,
+        Invalid(String),
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct ImportRow {
+        pub line: usize,
+        pub transaction: Option<Transaction>,
+        pub status: Status,
+    }
+
+    pub fn read_format(format: &str) -> Result<BrokerFormat, ImportError> {
+        let file = File::open("config/brokers.yml")?;
+        let mut formats: HashMap<String, BrokerFormat> = serde_yaml::from_reader(file)?;
+
+        let available = {
+            let mut names: Vec<&String> = formats.keys().collect();
+            names.sort();
+            names.iter().map(|name| name.as_str()).collect::<Vec<&str>>().join(", ")
+        };
+
+        formats.remove(format).ok_or(ImportError::UnknownFormat { format: format.to_string(), available })
+    }
+
+    /// Splits a CSV line, taking quoted fields (which may contain the delimiter) into account.
+    fn split_line(line: &str, delimiter: char) -> Vec<String> {
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut chars = line.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if quoted && chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                },
+                '"' => quoted = !quoted,
+                c if c == delimiter && !quoted => fields.push(std::mem::take(&mut field)),
+                c => field.push(c),
+            }
+        }
+        fields.push(field);
+
+        fields.into_iter().map(|field| field.trim().to_string()).collect()
+    }
+
+    fn parse_number(value: &str, decimal_comma: bool) -> Option<f64> {
+        let value = value.trim();
+
+        let normalised = if decimal_comma {
+            value.replace('.', "").replace(',', ".")
+        } else {
+            value.replace(',', "")
+        };
+
+        normalised.parse::<f64>().ok()
+    }
+
+    fn parse_row(fields: &[String], index: &HashMap<&str, usize>, format: &BrokerFormat) -> Result<Transaction, String> {
+        let field = |name: &str| -> &str {
+            index.get(name).and_then(|i| fields.get(*i)).map(|value| value.as_str()).unwrap_or("")
+        };
+
+        let raw_symbol = field("symbol");
+        if raw_symbol.is_empty() {
+            return Err("symbol is missing".to_string())
+        }
+        let symbol = format.symbols.get(raw_symbol).cloned().unwrap_or_else(|| raw_symbol.to_string()).to_lowercase();
+
+        let quantity = parse_number(field("quantity"), format.decimal_comma)
+            .ok_or(format!("quantity {:?} is not a number", field("quantity")))?;
+        let price = parse_number(field("price"), format.decimal_comma)
+            .ok_or(format!("price {:?} is not a number", field("price")))?;
+        let fee = match field("fee") {
+            "" => 0.0,
+            fee => parse_number(fee, format.decimal_comma).ok_or(format!("fee {:?} is not a number", fee))?
+        };
+        let date = NaiveDate::parse_from_str(field("date"), &format.date_format)
+            .map_err(|_| format!("date {:?} doesn't match {}", field("date"), format.date_format))?;
+
+        let kind = if format.columns.kind.is_some() {
+            let kind = field("kind");
+            if kind.eq_ignore_ascii_case(&format.buy) {
+                Kind::Buy
+            } else if kind.eq_ignore_ascii_case(&format.sell) {
+                Kind::Sell
+            } else {
+                return Err(format!("type {:?} is neither {:?} nor {:?}", kind, format.buy, format.sell))
+            }
+        } else if quantity < 0.0 {
+            Kind::Sell
+        } else {
+            Kind::Buy
+        };
+
+        Ok(Transaction { symbol, kind, quantity: quantity.abs(), price: price.abs(), fee: fee.abs(), date })
+    }
+
+    /// Reads the CSV file and checks every row in date order against the stored transactions 
+    /// and the rows before it. Rows are returned in that order, so they're also saved by date.
+    pub fn read_transactions(
+        path: &str, format: &BrokerFormat, existing: &[Transaction]
+    ) -> Result<Vec<ImportRow>, ImportError> {
+        let contents = file_to_string(&File::open(path)?);
+        let mut lines = contents.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+
+        let header = match lines.next() {
+            Some((_, header)) => split_line(header.trim_start_matches('\u{feff}'), format.delimiter),
+            None => return Ok(vec![])
+        };
+
+        let mut index: HashMap<&str, usize> = HashMap::new();
+        let columns = [
+            ("symbol", Some(&format.columns.symbol)),
+            ("kind", format.columns.kind.as_ref()),
+            ("quantity", Some(&format.columns.quantity)),
+            ("price", Some(&format.columns.price)),
+            ("fee", format.columns.fee.as_ref()),
+            ("date", Some(&format.columns.date)),
+        ];
+
+        for (name, column) in columns {
+            if let Some(column) = column {
+                match header.iter().position(|value| value == column) {
+                    Some(i) => { index.insert(name, i); },
+                    None => return Err(ImportError::MissingColumn { column: column.clone() })
+                }
+            }
+        }
+
+        let mut parsed: Vec<(usize, Result<Transaction, String>)> = lines
+            .map(|(i, line)| (i + 1, parse_row(&split_line(line, format.delimiter), &index, format)))
+            .collect();
+        // Exports are often sorted newest first, but a sale can only be checked after its purchases.
+        parsed.sort_by_key(|(_, transaction)| transaction.as_ref().ok().map(|transaction| transaction.date));
+
+        // Every stored transaction matches at most one row, so identical trades within the file are kept.
+        let mut stored: Vec<&Transaction> = existing.iter().collect();
+        let mut accepted: Vec<Transaction> = existing.to_vec();
+        let mut rows = Vec::new();
+
+        for (line, transaction) in parsed {
+            let row = match transaction {
+                Err(reason) => ImportRow { line, transaction: None, status: Status::Invalid(reason) },
+                Ok(transaction) => match stored.iter().position(|stored| **stored == transaction) {
+                    Some(i) => {
+                        stored.remove(i);
+                        ImportRow { line, transaction: Some(transaction), status: Status::Duplicate }
+                    },
+                    None => match validate(&accepted, &transaction) {
+                        Ok(_) => {
+                            accepted.push(transaction.clone());
+                            ImportRow { line, transaction: Some(transaction), status: Status::New }
+                        },
+                        Err(reason) => ImportRow { line, transaction: Some(transaction), status: Status::Invalid(reason) }
+                    }
+                }
+            };
+
+            rows.push(row);
+        }
+
+        Ok(rows)
+    }
+
+    /// Symbols of new transactions which aren't saved as stocks yet.
+    pub fn unknown_symbols(rows: &[ImportRow], known: &[String]) -> Vec<String> {
+        let mut symbols: Vec<String> = rows.iter()
+            .filter(|row| row.status == Status::New)
+            .filter_map(|row| row.transaction.as_ref().map(|transaction| transaction.symbol.clone()))
+            .filter(|symbol| !known.contains(symbol))
+            .collect();
+        symbols.sort();
+        symbols.dedup();
+        symbols
+    }
+
+    /// Marks the new rows of a symbol whose stock couldn't be added as invalid, so they're skipped.
+    pub fn reject_symbol(rows: &mut [ImportRow], symbol: &str) {
+        for row in rows.iter_mut().filter(|row| row.status == Status::New) {
+            if row.transaction.as_ref().is_some_and(|transaction| transaction.symbol == symbol) {
+                row.status = Status::Invalid(format!("stock {} could not be added", symbol.to_uppercase()));
+            }
+        }
+    }
+
+    pub fn print_preview(rows: &[ImportRow], unknown: &[String]) {
+        let table: Vec<Vec<String>> = rows.iter()
+            .map(|row| {
+                let mut cells = match &row.transaction {
+                    Some(transaction) => vec![
+                        row.line.to_string(),
+                        transaction.date.format("%d.%m.%Y").to_string(),
+                        transaction.kind.as_str().to_string(),
+                        transaction.symbol.to_uppercase(),
+                        format!("{}", transaction.quantity),
+                        format!("{:.2}", transaction.price),
+                        format!("{:.2}", transaction.fee),
+                    ],
+                    None => vec![row.line.to_string(), "".to_string(), "".to_string(), "".to_string(), "".to_string(), "".to_string(), "".to_string()]
+                };
+
+                cells.push(match &row.status {
+                    Status::New => "new".to_string(),
+                    Status::Duplicate => "duplicate".to_string(),
+                    Status::Invalid(reason) => format!("invalid: {}", reason),
+                });
+
+                cells
+            })
+            .collect();
+
+        print_table(&["Line", "Date", "Type", "Symbol", "Quantity", "Price", "Fee", "Status"], &table);
+
+        let count = |status: &Status| rows.iter().filter(|row| row.status == *status).count();
+        let invalid = rows.iter().filter(|row| matches!(row.status, Status::Invalid(_))).count();
+
+        println!();
+        println!("{} new, {} duplicates, {} invalid", count(&Status::New), count(&Status::Duplicate), invalid);
+
+        if !unknown.is_empty() {
+            println!("Stocks which will be added: {}", unknown.join(", ").to_uppercase());
+        }
+    }
+}
@@ -0,0 +1,133 @@
+pub mod positions {
+    use std::collections::HashMap;
+
+    use crate::output::table::table::print_table;
+    use crate::portfolio::transactions::transactions::{Kind, Transaction};
+
+    #[derive(Debug, Clone)]
+    pub struct Position {
+        pub symbol: String,
+        pub quantity: f64,
+        pub cost_basis: f64,
+    }
+
+    impl Position {
+        pub fn average_cost(&self) -> f64 {
+            if self.quantity > 0.0 {
+                self.cost_basis / self.quantity
+            } else {
+                0.0
+            }
+        }
+    }
+
+    /// Builds the open positions with the average cost method. Fees of buys are part
+    /// of the cost basis, sells reduce it by the average cost of the sold shares.
+    pub fn positions(transactions: &[Transaction]) -> Vec<Position> {
+        let mut sorted = transactions.to_vec();
+        sorted.sort_by_key(|transaction| transaction.date);
+
+        let mut positions: Vec<Position> = Vec::new();
+
+        for transaction in sorted {
+            let index = match positions.iter().position(|position| position.symbol == transaction.symbol) {
+                Some(index) => index,
+                None => {
+                    positions.push(Position { symbol: transaction.symbol.clone(), quantity: 0.0, cost_basis: 0.0 });
+                    positions.len() - 1
+                }
+            };
+
+            let position = &mut positions[index];
+
+            match transaction.kind {
+                Kind::Buy => {
+                    position.cost_basis += transaction.quantity * transaction.price + transaction.fee;
+                    position.quantity += transaction.quantity;
+                },
+                Kind::Sell => {
+                    let sold = transaction.quantity.min(position.quantity);
+                    position.cost_basis -= position.average_cost() * sold;
+                    position.quantity -= sold;
+                }
+            }
+        }
+
+        positions.into_iter()
+            .filter(|position| position.quantity > f64::EPSILON)
+            .collect()
+    }
+
+    /// Market value of all positions which have a stored price.
+    pub fn market_value(positions: &[Position], prices: &HashMap<String, f64>) -> f64 {
+        positions.iter()
+            .filter_map(|position| prices.get(&position.symbol).map(|price| price * position.quantity))
+            .sum()
+    }
+
+    pub fn print_portfolio(transactions: &[Transaction], prices: &HashMap<String, f64>) {
+        let positions = positions(transactions);
+
+        if positions.is_empty() {
+            return println!("There are no open positions. Run 'buy' to record a transaction.")
+        }
+
+        let total_value = market_value(&positions, prices);
+        let mut total_cost = 0.0;
+        let mut missing_prices = Vec::new();
+
+        let mut rows: Vec<Vec<String>> = Vec::new();
+
+        for position in &positions {
+            match prices.get(&position.symbol) {
+                Some(price) => {
+                    let value = price * position.quantity;
+                    let pnl = value - position.cost_basis;
+                    total_cost += position.cost_basis;
+
+                    rows.push(vec![
+                        position.symbol.to_uppercase(),
+                        format!("{}", position.quantity),
+                        format!("{:.2}", position.average_cost()),
+                        format!("{:.2}", price),
+                        format!("{:.2}", value),
+                        format!("{:.2}", pnl),
+                        format!("{:.2}%", if position.cost_basis > 0.0 { pnl / position.cost_basis * 100.0 } else { 0.0 }),
+                        format!("{:.2}%", if total_value > 0.0 { value / total_value * 100.0 } else { 0.0 }),
+                    ]);
+                },
+                None => {
+                    missing_prices.push(position.symbol.to_uppercase());
+
+                    rows.push(vec![
+                        position.symbol.to_uppercase(),
+                        format!("{}", position.quantity),
+                        format!("{:.2}", position.average_cost()),
+                        "-".to_string(), "-".to_string(), "-".to_string(), "-".to_string(), "-".to_string(),
+                    ]);
+                }
+            }
+        }
+
+        let total_pnl = total_value - total_cost;
+
+        rows.push(vec![
+            "Total".to_string(), "".to_string(), "".to_string(), "".to_string(),
+            format!("{:.2}", total_value),
+            format!("{:.2}", total_pnl),
+            format!("{:.2}%", if total_cost > 0.0 { total_pnl / total_cost * 100.0 } else { 0.0 }),
+            "100.00%".to_string(),
+        ]);
+
+        print_table(
+            &["Symbol", "Quantity", "Avg Cost", "Price", "Market Value", "Unrealised P&L", "P&L %", "Weight"], 
+            &rows
+        );
+
+        if !missing_prices.is_empty() {
+            println!();
+            println!("No current price stored for: {}", missing_prices.join(", "));
+            println!("Run 'add' for these stocks so they are included in the market value.");
+        }
+    }
+}
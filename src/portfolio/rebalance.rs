@@ -0,0 +1,239 @@
+pub mod rebalance {
+    use std::{collections::{BTreeMap, HashMap}, fs::File};
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::errors::error_handler::error_handler::TargetError;
+    use crate::output::table::table::print_table;
+    use crate::portfolio::positions::positions::positions;
+    use crate::portfolio::transactions::transactions::Transaction;
+
+    const TARGETS_FILE: &str = "config/targets.yml";
+
+    /// Target weights in percent, either per symbol or per tag. A tag is a named list.
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct Targets {
+        #[serde(default)]
+        pub symbols: BTreeMap<String, f64>,
+        #[serde(default)]
+        pub tags: BTreeMap<String, f64>,
+    }
+
+    pub fn read_targets() -> Result<Targets, TargetError> {
+        match File::open(TARGETS_FILE) {
+            Ok(file) => Ok(serde_yaml::from_reader(file)?),
+            Err(_) => Ok(Targets::default())
+        }
+    }
+
+    pub fn save_targets(targets: &Targets) -> Result<(), TargetError> {
+        let file = File::create(TARGETS_FILE)?;
+        serde_yaml::to_writer(file, targets)?;
+
+        Ok(())
+    }
+
+    /// Sets the weight of a symbol or tag target, or removes it if no weight is given.
+    pub fn set_target(name: String, weight: Option<f64>, tag: bool) -> Result<(), TargetError> {
+        let mut targets = read_targets()?;
+        let map = if tag { &mut targets.tags } else { &mut targets.symbols };
+
+        match weight {
+            Some(weight) => { map.insert(name, weight); },
+            None => { map.remove(&name); }
+        }
+
+        save_targets(&targets)
+    }
+
+    pub fn print_targets(targets: &Targets) {
+        if targets.symbols.is_empty() && targets.tags.is_empty() {
+            return println!("No targets are set. Run 'target set SYMBOL WEIGHT'.")
+        }
+
+        let mut rows: Vec<Vec<String>> = targets.symbols.iter()
+            .map(|(symbol, weight)| vec![symbol.to_uppercase(), "symbol".to_string(), format!("{:.2}%", weight)])
+            .collect();
+        rows.extend(targets.tags.iter()
+            .map(|(tag, weight)| vec![tag.clone(), "tag".to_string(), format!("{:.2}%", weight)]));
+
+        print_table(&["Name", "Type", "Target"], &rows);
+        println!("Total: {:.2}%", targets.symbols.values().sum::<f64>() + targets.tags.values().sum::<f64>());
+    }
+
+    /// Target weight per symbol. The weight of a tag is split evenly between its stocks 
+    /// which don't have their own target, whether they're held or not.
+    pub fn symbol_weights(
+        targets: &Targets, tags: &HashMap<String, Vec<String>>
+    ) -> BTreeMap<String, f64> {
+        let mut weights: BTreeMap<String, f64> = targets.symbols.iter()
+            .map(|(symbol, weight)| (symbol.to_lowercase(), *weight))
+            .collect();
+
+        for (tag, tag_weight) in &targets.tags {
+            let members: Vec<String> = match tags.get(tag) {
+                Some(members) => members.iter()
+                    .filter(|member| !targets.symbols.contains_key(*member))
+                    .cloned()
+                    .collect(),
+                None => {
+                    println!("List {} of a target doesn't exist, so it's ignored.", tag);
+                    continue
+                }
+            };
+
+            let members: Vec<String> = members.into_iter()
+                .filter(|member| {
+                    let assigned = weights.contains_key(member);
+                    if assigned {
+                        println!("{} is part of several target lists, only the first one is used.", member.to_uppercase());
+                    }
+                    !assigned
+                })
+                .collect();
+
+            if members.is_empty() {
+                continue
+            }
+
+            for member in &members {
+                weights.insert(member.clone(), tag_weight / members.len() as f64);
+            }
+        }
+
+        weights
+    }
+
+    pub struct Trade {
+        pub symbol: String,
+        pub price: f64,
+        pub current_value: f64,
+        pub target_weight: f64,
+        pub target_value: f64,
+        pub shares: f64,
+    }
+
+    impl Trade {
+        pub fn amount(&self) -> f64 {
+            self.shares * self.price
+        }
+    }
+
+    /// Trades which bring the stocks with a target to their weight. Holdings without 
+    /// a target are left as they are.
+    pub fn trades(
+        weights: &BTreeMap<String, f64>,
+        quantities: &HashMap<String, f64>,
+        prices: &HashMap<String, f64>,
+        cash: f64,
+        min_trade: f64,
+        whole_shares: bool,
+    ) -> Vec<Trade> {
+        let total: f64 = quantities.iter()
+            .filter_map(|(symbol, quantity)| prices.get(symbol).map(|price| price * quantity))
+            .sum::<f64>() + cash;
+
+        let mut trades = Vec::new();
+
+        for (symbol, target_weight) in weights {
+            let price = match prices.get(symbol) {
+                Some(price) if *price > 0.0 => *price,
+                _ => {
+                    println!("No current price stored for {}, so it's skipped. Run 'add {}'.", symbol.to_uppercase(), symbol);
+                    continue
+                }
+            };
+
+            let current_value = quantities.get(symbol).copied().unwrap_or(0.0) * price;
+            let target_weight = *target_weight;
+            let target_value = total * target_weight / 100.0;
+
+            let mut shares = (target_value - current_value) / price;
+            if whole_shares {
+                shares = shares.trunc();
+            }
+
+            if (shares * price).abs() < min_trade.max(0.01) {
+                shares = 0.0;
+            }
+
+            trades.push(Trade { symbol: symbol.clone(), price, current_value, target_weight, target_value, shares });
+        }
+
+        trades
+    }
+
+    pub fn print_rebalance(
+        transactions: &[Transaction],
+        prices: &HashMap<String, f64>,
+        tags: &HashMap<String, Vec<String>>,
+        cash: f64,
+        min_trade: f64,
+        whole_shares: bool,
+    ) {
+        let targets = match read_targets() {
+            Ok(targets) => targets,
+            Err(e) => return println!("{}", e)
+        };
+
+        if targets.symbols.is_empty() && targets.tags.is_empty() {
+            return println!("No targets are set. Run 'target set SYMBOL WEIGHT'.")
+        }
+
+        let total_weight = targets.symbols.values().sum::<f64>() + targets.tags.values().sum::<f64>();
+        if total_weight > 100.0 + f64::EPSILON {
+            return println!("Targets add up to {:.2}%, which is more than 100%.", total_weight)
+        }
+
+        let quantities: HashMap<String, f64> = positions(transactions).into_iter()
+            .map(|position| (position.symbol, position.quantity))
+            .collect();
+        let values: HashMap<String, f64> = quantities.iter()
+            .filter_map(|(symbol, quantity)| prices.get(symbol).map(|price| (symbol.clone(), price * quantity)))
+            .collect();
+
+        let weights = symbol_weights(&targets, tags);
+        let trades = trades(&weights, &quantities, prices, cash, min_trade, whole_shares);
+        let total = values.values().sum::<f64>() + cash;
+
+        let rows: Vec<Vec<String>> = trades.iter()
+            .map(|trade| vec![
+                trade.symbol.to_uppercase(),
+                format!("{:.2}", trade.price),
+                format!("{:.2}", trade.current_value),
+                format!("{:.2}%", if total > 0.0 { trade.current_value / total * 100.0 } else { 0.0 }),
+                format!("{:.2}%", trade.target_weight),
+                format!("{:.2}", trade.target_value),
+                if trade.shares > 0.0 { "Buy" } else if trade.shares < 0.0 { "Sell" } else { "-" }.to_string(),
+                format!("{:.4}", trade.shares.abs()),
+                format!("{:.2}", trade.amount().abs()),
+            ])
+            .collect();
+
+        print_table(
+            &["Symbol", "Price", "Value", "Weight", "Target", "Target Value", "Trade", "Shares", "Amount"], 
+            &rows
+        );
+
+        let remaining_cash = cash - trades.iter().map(|trade| trade.amount()).sum::<f64>();
+        let mut untargeted: Vec<String> = values.keys()
+            .filter(|symbol| !weights.contains_key(*symbol))
+            .map(|symbol| symbol.to_uppercase())
+            .collect();
+        untargeted.sort();
+
+        println!();
+        println!("Cash before: {:.2}", cash);
+        println!("Cash after: {:.2}", remaining_cash);
+
+        if !untargeted.is_empty() {
+            println!("Holdings without a target are left as they are: {}", untargeted.join(", "));
+        }
+
+        if remaining_cash < -0.01 {
+            println!("Together with the holdings without a target, the targets need more cash than you have.");
+        } else if total_weight < 100.0 && untargeted.is_empty() {
+            println!("Targets add up to {:.2}%, the rest stays in cash.", total_weight);
+        }
+    }
+}
@@ -0,0 +1,306 @@
+pub mod returns {
+    use std::collections::HashMap;
+
+    use chrono::{Duration, NaiveDate};
+
+    use crate::portfolio::transactions::transactions::{Kind, Transaction};
+    use crate::scraper::financial_data::get_financial_data::historical_prices;
+
+    pub struct Returns {
+        pub from: NaiveDate,
+        pub to: NaiveDate,
+        pub start_value: f64,
+        pub end_value: f64,
+        pub net_deposits: f64,
+        pub time_weighted: f64,
+        pub money_weighted: Option<f64>,
+        /// Return of every trading day, adjusted for cash flows.
+        pub daily_returns: Vec<(NaiveDate, f64)>,
+    }
+
+    /// Closes per symbol; the price of a date is the last close on or before it.
+    struct PriceHistory {
+        prices: HashMap<String, Vec<(NaiveDate, f64)>>,
+    }
+
+    impl PriceHistory {
+        fn price(&self, symbol: &str, date: NaiveDate) -> Option<f64> {
+            self.prices.get(symbol)?
+                .iter()
+                .take_while(|(price_date, _)| *price_date <= date)
+                .last()
+                .map(|(_, price)| *price)
+        }
+
+        fn value(&self, holdings: &HashMap<String, f64>, date: NaiveDate) -> f64 {
+            holdings.iter()
+                .filter_map(|(symbol, quantity)| self.price(symbol, date).map(|price| price * quantity))
+                .sum()
+        }
+    }
+
+    fn apply(holdings: &mut HashMap<String, f64>, transaction: &Transaction) {
+        let quantity = holdings.entry(transaction.symbol.clone()).or_insert(0.0);
+
+        match transaction.kind {
+            Kind::Buy => *quantity += transaction.quantity,
+            Kind::Sell => *quantity -= transaction.quantity,
+        }
+    }
+
+    /// Cash the investor put into the portfolio (positive) or took out of it (negative).
+    fn deposit(transaction: &Transaction) -> f64 {
+        match transaction.kind {
+            Kind::Buy => transaction.quantity * transaction.price + transaction.fee,
+            Kind::Sell => -(transaction.quantity * transaction.price - transaction.fee),
+        }
+    }
+
+    /// Return of a day. Purchases are made at the start of the day, so their cost is part of
+    /// the base, and sales at its end, so their proceeds are part of the day's value.
+    fn day_return(previous_value: f64, value: f64, purchases: f64, sales: f64) -> Option<f64> {
+        let base = previous_value + purchases;
+
+        match base > 0.0 {
+            true => Some((value + sales) / base - 1.0),
+            false => None
+        }
+    }
+
+    pub async fn calculate(transactions: &[Transaction], from: NaiveDate, to: NaiveDate) -> Option<Returns> {
+        let mut symbols: Vec<String> = transactions.iter().map(|transaction| transaction.symbol.clone()).collect();
+        symbols.sort();
+        symbols.dedup();
+
+        let mut history = PriceHistory { prices: HashMap::new() };
+
+        for symbol in symbols {
+            match historical_prices(&symbol, from - Duration::days(10), to).await {
+                Ok(prices) => { history.prices.insert(symbol, prices); },
+                Err(e) => println!("{}", e)
+            }
+        }
+
+        returns(transactions, &history, from, to)
+    }
+
+    fn returns(transactions: &[Transaction], history: &PriceHistory, from: NaiveDate, to: NaiveDate) -> Option<Returns> {
+        let mut dates: Vec<NaiveDate> = history.prices.values()
+            .flat_map(|prices| prices.iter().map(|(date, _)| *date))
+            .filter(|date| *date > from && *date <= to)
+            .collect();
+        dates.sort();
+        dates.dedup();
+
+        let mut holdings: HashMap<String, f64> = HashMap::new();
+        let mut sorted = transactions.to_vec();
+        sorted.sort_by_key(|transaction| transaction.date);
+
+        let mut pending = sorted.iter().peekable();
+        while let Some(transaction) = pending.next_if(|transaction| transaction.date <= from) {
+            apply(&mut holdings, transaction);
+        }
+
+        let start_value = history.value(&holdings, from);
+        let mut previous_value = start_value;
+        let mut growth = 1.0;
+        let mut net_deposits = 0.0;
+        let mut cash_flows = vec![(from, -start_value)];
+        let mut daily_returns = Vec::new();
+
+        for date in dates {
+            let (mut purchases, mut sales) = (0.0, 0.0);
+
+            while let Some(transaction) = pending.next_if(|transaction| transaction.date <= date) {
+                apply(&mut holdings, transaction);
+                match transaction.kind {
+                    Kind::Buy => purchases += deposit(transaction),
+                    Kind::Sell => sales -= deposit(transaction),
+                }
+                cash_flows.push((transaction.date, -deposit(transaction)));
+            }
+
+            let value = history.value(&holdings, date);
+
+            if let Some(day_return) = day_return(previous_value, value, purchases, sales) {
+                growth *= 1.0 + day_return;
+                daily_returns.push((date, day_return));
+            }
+
+            net_deposits += purchases - sales;
+            previous_value = value;
+        }
+
+        // Transactions after the last trading day of the period, e.g. on a weekend.
+        while let Some(transaction) = pending.next_if(|transaction| transaction.date <= to) {
+            apply(&mut holdings, transaction);
+            net_deposits += deposit(transaction);
+            cash_flows.push((transaction.date, -deposit(transaction)));
+            previous_value = history.value(&holdings, to);
+        }
+
+        if start_value <= 0.0 && net_deposits == 0.0 {
+            return None
+        }
+
+        cash_flows.push((to, previous_value));
+
+        Some(Returns {
+            from,
+            to,
+            start_value,
+            end_value: previous_value,
+            net_deposits,
+            time_weighted: growth - 1.0,
+            money_weighted: xirr(&cash_flows),
+            daily_returns,
+        })
+    }
+
+    fn net_present_value(cash_flows: &[(NaiveDate, f64)], rate: f64) -> f64 {
+        let first = cash_flows[0].0;
+
+        cash_flows.iter()
+            .map(|(date, amount)| amount / (1.0 + rate).powf((*date - first).num_days() as f64 / 365.0))
+            .sum()
+    }
+
+    /// Annual rate at which the net present value of all cash flows is zero.
+    /// Solved by bisection, which is slower than Newton's method but can't diverge.
+    pub fn xirr(cash_flows: &[(NaiveDate, f64)]) -> Option<f64> {
+        let cash_flows: Vec<(NaiveDate, f64)> = cash_flows.iter().copied().filter(|(_, amount)| *amount != 0.0).collect();
+
+        if !cash_flows.iter().any(|(_, amount)| *amount > 0.0) || !cash_flows.iter().any(|(_, amount)| *amount < 0.0) {
+            return None
+        }
+
+        let (mut low, mut high) = (-0.9999, 10.0);
+        let mut low_value = net_present_value(&cash_flows, low);
+
+        if low_value.signum() == net_present_value(&cash_flows, high).signum() {
+            return None
+        }
+
+        for _ in 0..200 {
+            let mid = (low + high) / 2.0;
+            let mid_value = net_present_value(&cash_flows, mid);
+
+            if mid_value.abs() < 1e-9 {
+                return Some(mid)
+            }
+
+            if mid_value.signum() == low_value.signum() {
+                low = mid;
+                low_value = mid_value;
+            } else {
+                high = mid;
+            }
+        }
+
+        Some((low + high) / 2.0)
+    }
+
+    pub fn annualise(total_return: f64, from: NaiveDate, to: NaiveDate) -> f64 {
+        let years = (to - from).num_days() as f64 / 365.25;
+
+        if years <= 0.0 {
+            return total_return
+        }
+
+        (1.0 + total_return).powf(1.0 / years) - 1.0
+    }
+
+    pub async fn print_returns(transactions: &[Transaction], from: NaiveDate, to: NaiveDate) {
+        if from >= to {
+            return println!("The start date needs to lie before the end date.")
+        }
+
+        let returns = match calculate(transactions, from, to).await {
+            Some(returns) => returns,
+            None => return println!("There were no positions in this period.")
+        };
+
+        let gain = returns.end_value - returns.start_value - returns.net_deposits;
+
+        println!("Portfolio returns {} - {}", returns.from.format("%d.%m.%Y"), returns.to.format("%d.%m.%Y"));
+        println!("  - Start Value: {:.2}", returns.start_value);
+        println!("  - End Value: {:.2}", returns.end_value);
+        println!("  - Net Deposits: {:.2}", returns.net_deposits);
+        println!("  - Gain: {:.2}", gain);
+        println!("  - Time-Weighted Return: {:.2}%", returns.time_weighted * 100.0);
+        println!(
+            "  - Time-Weighted Return (annualised): {:.2}%", 
+            annualise(returns.time_weighted, returns.from, returns.to) * 100.0
+        );
+        match returns.money_weighted {
+            Some(rate) => println!("  - Money-Weighted Return (XIRR, annualised): {:.2}%", rate * 100.0),
+            None => println!("  - Money-Weighted Return (XIRR, annualised): could not be calculated")
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn date(day: u32) -> NaiveDate {
+            NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+        }
+
+        fn transaction(kind: Kind, quantity: f64, price: f64, day: u32) -> Transaction {
+            Transaction { symbol: "aapl".to_string(), kind, quantity, price, fee: 0.0, date: date(day) }
+        }
+
+        /// Closes of 100 on the 1st, 95 on the 2nd and 105 on the 3rd.
+        fn history() -> PriceHistory {
+            PriceHistory {
+                prices: HashMap::from([("aapl".to_string(), vec![(date(1), 100.0), (date(2), 95.0), (date(3), 105.0)])])
+            }
+        }
+
+        fn assert_close(actual: f64, expected: f64) {
+            assert!((actual - expected).abs() < 1e-9, "{} != {}", actual, expected);
+        }
+
+        #[test]
+        fn buy_counts_from_the_start_of_the_day() {
+            let transactions = [transaction(Kind::Buy, 10.0, 100.0, 1), transaction(Kind::Buy, 10.0, 100.0, 2)];
+            let returns = returns(&transactions, &history(), date(1), date(2)).unwrap();
+
+            // 1000 held plus 1000 bought at 100 are worth 1900 at a close of 95.
+            assert_close(returns.time_weighted, -0.05);
+            assert_close(returns.net_deposits, 1000.0);
+        }
+
+        #[test]
+        fn partial_sell_counts_until_the_end_of_the_day() {
+            let transactions = [transaction(Kind::Buy, 10.0, 100.0, 1), transaction(Kind::Sell, 4.0, 98.0, 2)];
+            let returns = returns(&transactions, &history(), date(1), date(2)).unwrap();
+
+            // 6 shares at 95 plus the proceeds of 392 against 1000 at the start of the day.
+            assert_close(returns.time_weighted, (570.0 + 392.0) / 1000.0 - 1.0);
+            assert_close(returns.end_value, 570.0);
+        }
+
+        #[test]
+        fn full_liquidation_keeps_the_return_of_the_sale() {
+            let below = [transaction(Kind::Buy, 10.0, 100.0, 1), transaction(Kind::Sell, 10.0, 95.0, 2)];
+            assert_close(returns(&below, &history(), date(1), date(3)).unwrap().time_weighted, -0.05);
+
+            let above = [transaction(Kind::Buy, 10.0, 100.0, 1), transaction(Kind::Sell, 10.0, 103.0, 2)];
+            assert_close(returns(&above, &history(), date(1), date(3)).unwrap().time_weighted, 0.03);
+        }
+
+        #[test]
+        fn xirr_of_a_year() {
+            let cash_flows = [(date(1), -1000.0), (date(1) + Duration::days(365), 1100.0)];
+
+            assert_close(xirr(&cash_flows).unwrap(), 0.1);
+        }
+
+        #[test]
+        fn xirr_needs_flows_in_both_directions() {
+            assert_eq!(xirr(&[(date(1), -1000.0), (date(2), -100.0)]), None);
+            assert_eq!(xirr(&[]), None);
+        }
+    }
+}
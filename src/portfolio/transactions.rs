@@ -0,0 +1,113 @@
+pub mod transactions {
+    use chrono::{Local, NaiveDate};
+
+    use crate::scraper::financial_data::get_financial_data::parse_naive_date;
+
+    pub const DATE_FORMAT: &str = "%Y-%m-%d";
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Kind {
+        Buy,
+        Sell,
+    }
+
+    impl Kind {
+        pub fn as_str(&self) -> &'static str {
+            match self {
+                Kind::Buy => "buy",
+                Kind::Sell => "sell",
+            }
+        }
+
+        pub fn parse(kind: &str) -> Option<Kind> {
+            match kind.trim().to_lowercase().as_str() {
+                "buy" => Some(Kind::Buy),
+                "sell" => Some(Kind::Sell),
+                _ => None
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Transaction {
+        pub symbol: String,
+        pub kind: Kind,
+        pub quantity: f64,
+        pub price: f64,
+        pub fee: f64,
+        pub date: NaiveDate,
+    }
+
+    impl Transaction {
+        pub fn new(
+            kind: Kind, symbol: String, quantity: f64, price: f64, date: Option<String>, fee: f64
+        ) -> Result<Transaction, String> {
+            let date = match date {
+                Some(date) => parse_naive_date(date)?,
+                None => Local::now().date_naive()
+            };
+
+            if date > Local::now().date_naive() {
+                return Err("The entered date lies in the future. Please provide a date from the past.".to_string())
+            }
+
+            Ok(Transaction { symbol: symbol.to_lowercase(), kind, quantity, price, fee, date })
+        }
+    }
+
+    /// Quantity of the symbol held after all given transactions.
+    pub fn quantity_held(transactions: &[Transaction], symbol: &str) -> f64 {
+        transactions.iter()
+            .filter(|transaction| transaction.symbol == symbol)
+            .map(|transaction| match transaction.kind {
+                Kind::Buy => transaction.quantity,
+                Kind::Sell => -transaction.quantity,
+            })
+            .sum()
+    }
+
+    /// Checks whether a new transaction is valid given the already stored ones.
+    pub fn validate(transactions: &[Transaction], transaction: &Transaction) -> Result<(), String> {
+        if transaction.quantity <= 0.0 {
+            return Err("Quantity needs to be greater than 0.".to_string())
+        }
+
+        if transaction.price < 0.0 || transaction.fee < 0.0 {
+            return Err("Price and fee can't be negative.".to_string())
+        }
+
+        // A backdated sale can leave a later, already stored sale without shares, 
+        // so every transaction of the symbol is replayed in date order.
+        if transaction.kind == Kind::Sell {
+            let mut replayed: Vec<&Transaction> = transactions.iter()
+                .filter(|stored| stored.symbol == transaction.symbol)
+                .chain(std::iter::once(transaction))
+                .collect();
+            replayed.sort_by_key(|replayed| replayed.date);
+
+            let mut quantity = 0.0;
+            for replayed in replayed {
+                let held = quantity;
+                quantity += match replayed.kind {
+                    Kind::Buy => replayed.quantity,
+                    Kind::Sell => -replayed.quantity,
+                };
+
+                if quantity < -f64::EPSILON {
+                    return Err(match std::ptr::eq(replayed, transaction) {
+                        true => format!(
+                            "You only held {} shares of {} on {}.", 
+                            held, transaction.symbol.to_uppercase(), transaction.date.format("%d.%m.%Y")
+                        ),
+                        false => format!(
+                            "This sale leaves too few shares of {} for the sale of {} shares on {}.", 
+                            transaction.symbol.to_uppercase(), replayed.quantity, replayed.date.format("%d.%m.%Y")
+                        )
+                    })
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
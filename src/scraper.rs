@@ -1 +1,2 @@
-pub mod financial_data;
\ No newline at end of file
+pub mod financial_data;
+pub mod timeseries;
\ No newline at end of file
@@ -1,13 +1,16 @@
 pub mod get_financial_data {
     use scraper::Html;
     use regex::Regex;
-    use chrono::prelude::*;
+    use chrono::{prelude::*, Duration};
+    use chrono_tz::Tz;
     use serde::Deserialize;
+    use std::collections::HashMap;
     use tokio::task;
 
     use crate::{
         errors::error_handler::error_handler::YahooError as YahooError, 
         file::cmd::cmd::historical_price, 
+        output::{table::table::print_table, locale::locale::Locale},
         parse_date
     };
 
@@ -23,7 +26,35 @@ pub mod get_financial_data {
 
     #[derive(Deserialize, Debug)]
     pub struct QuoteBlock {
+        #[serde(default)]
+        pub meta: Meta,
+        #[serde(default)]
+        pub timestamp: Vec<i64>,
         pub indicators: Quote,
+        #[serde(default)]
+        pub events: Events,
+    }
+
+    #[derive(Deserialize, Debug, Default)]
+    pub struct Events {
+        #[serde(default)]
+        pub dividends: HashMap<String, DividendEvent>,
+    }
+
+    #[derive(Deserialize, Debug, Clone)]
+    pub struct DividendEvent {
+        pub amount: f64,
+        pub date: i64,
+    }
+
+    #[derive(Deserialize, Debug, Default, Clone)]
+    pub struct Meta {
+        #[serde(default)]
+        pub gmtoffset: i32,
+        #[serde(default)]
+        pub timezone: String,
+        #[serde(default, rename = "exchangeTimezoneName")]
+        pub exchange_timezone_name: String,
     }
 
     #[derive(Deserialize, Debug)]
@@ -33,73 +64,149 @@ pub mod get_financial_data {
 
     #[derive(Deserialize, Debug)]
     pub struct QuoteList {
+        #[serde(default)]
+        pub open: Vec<Option<f64>>,
+        #[serde(default)]
+        pub high: Vec<Option<f64>>,
+        #[serde(default)]
+        pub low: Vec<Option<f64>>,
         pub close: Vec<Option<f64>>,
+        #[serde(default)]
+        pub volume: Vec<Option<u64>>,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Bar {
+        pub time: DateTime<FixedOffset>,
+        pub open: f64,
+        pub high: f64,
+        pub low: f64,
+        pub close: f64,
+        pub volume: u64,
+    }
+
+    impl Meta {
+        /// The time of a timestamp in the time zone of the exchange. The offset is resolved 
+        /// for that time, so times across a change to or from daylight saving time are right. 
+        /// Yahoo's current offset is only used if the time zone isn't known.
+        pub fn time(&self, timestamp: i64) -> Option<DateTime<FixedOffset>> {
+            match self.exchange_timezone_name.parse::<Tz>() {
+                Ok(tz) => {
+                    let time = tz.timestamp_opt(timestamp, 0).single()?;
+                    Some(time.with_timezone(&time.offset().fix()))
+                },
+                Err(_) => FixedOffset::east_opt(self.gmtoffset)
+                    .unwrap_or_else(|| FixedOffset::east_opt(0).unwrap())
+                    .timestamp_opt(timestamp, 0)
+                    .single()
+            }
+        }
+    }
+
+    impl QuoteBlock {
+        /// Returns all bars with a close price, timestamps in the time zone of the exchange.
+        pub fn bars(&self) -> Vec<Bar> {
+            let quote = match self.indicators.quote.first() {
+                Some(quote) => quote,
+                None => return vec![]
+            };
+
+            let value = |values: &Vec<Option<f64>>, i: usize, close: f64| {
+                values.get(i).copied().flatten().unwrap_or(close)
+            };
+
+            self.timestamp.iter()
+                .enumerate()
+                .filter_map(|(i, timestamp)| {
+                    let close = quote.close.get(i).copied().flatten()?;
+                    let time = self.meta.time(*timestamp)?;
+
+                    Some(Bar {
+                        time,
+                        open: value(&quote.open, i, close),
+                        high: value(&quote.high, i, close),
+                        low: value(&quote.low, i, close),
+                        close,
+                        volume: quote.volume.get(i).copied().flatten().unwrap_or(0),
+                    })
+                })
+                .collect()
+        }
     }
 
     pub struct Url {
         pub symbol: String,
     }
 
+    /// Fetches a page. Network errors are returned, so a failed request only affects its stock.
+    async fn fetch(url: String, symbol: &str) -> Result<Html, YahooError> {
+        let response = task::spawn_blocking(move || {
+            reqwest::blocking::get(url).and_then(|response| response.text())
+        }).await;
+
+        match response {
+            Ok(Ok(response)) => Ok(scraper::Html::parse_document(&response)),
+            _ => Err(YahooError::RequestFailed { value: symbol.to_string() })
+        }
+    }
+
     impl Url {
-        pub async fn scrapped_home(&self) -> Html {
+        pub async fn scrapped_home(&self) -> Result<Html, YahooError> {
             let url = format!(
                 "https://finance.yahoo.com/quote/{}?p={}&.tsrc=fin-srch", self.symbol, self.symbol
             );
 
-            let response = task::spawn_blocking(move || {
-                reqwest::blocking::get(url).unwrap().text().unwrap()
-            }).await.unwrap();
-            
-            scraper::Html::parse_document(&response)
+            fetch(url, &self.symbol).await
         }
 
-        pub async fn scrapped_statistics(&self) -> Html {
+        pub async fn scrapped_statistics(&self) -> Result<Html, YahooError> {
             let url = format!(
                 "https://finance.yahoo.com/quote/{}/key-statistics?p={}", 
                 self.symbol, self.symbol
             );
 
-            let response = task::spawn_blocking(move || {
-                reqwest::blocking::get(url).unwrap().text().unwrap()
-            }).await.unwrap();
-    
-            scraper::Html::parse_document(&response)
+            fetch(url, &self.symbol).await
+        }
+
+        pub async fn scrapped_profile(&self) -> Result<Html, YahooError> {
+            let url = format!(
+                "https://finance.yahoo.com/quote/{}/profile?p={}", 
+                self.symbol, self.symbol
+            );
+
+            fetch(url, &self.symbol).await
         }
     }
 
     #[derive(Clone)]
     pub struct StockData {
+        #[allow(dead_code)]
         pub symbol: String,
         pub url: Html,
     }
 
     impl StockData {
         pub fn trailing_eps(&self) -> f64 {
-            key_value_from_summary(
-                &self.url, 
-                "td[data-test='EPS_RATIO-value']"
-            ).parse::<f64>().unwrap()
+            number_or_zero(key_value_from_summary(&self.url, "td[data-test='EPS_RATIO-value']"), "EPS (ttm)")
         }
 
         pub fn pe_ratio(&self) -> f64 {
-            key_value_from_summary(
-                &self.url, 
-                "td[data-test='PE_RATIO-value']"
-            ).parse::<f64>().unwrap()
+            number_or_zero(key_value_from_summary(&self.url, "td[data-test='PE_RATIO-value']"), "PE Ratio (ttm)")
         }
 
-        pub fn current_price(&self) -> f64 {
-            key_value_from_summary(
-                &self.url, 
-                "fin-streamer[data-test='qsp-price']"
-            ).parse::<f64>().unwrap()
+        /// Without a price nothing about the stock can be trusted, so it's the one value
+        /// whose absence fails the whole scrape.
+        pub fn current_price(&self) -> Result<f64, YahooError> {
+            key_value_from_summary(&self.url, "fin-streamer[data-test='qsp-price']")
+                .and_then(|price| price.replace(',', "").parse::<f64>().ok())
+                .ok_or(YahooError::ParseError { value: "Current Price".to_string() })
         }
         
         pub fn change_since(&self) -> String {
             let change_in_percentage = key_value_from_summary(
                 &self.url, 
                 "div[id='quote-header-info'] fin-streamer[data-field='regularMarketChangePercent'] span"
-            );
+            ).unwrap_or_default();
 
             let string_date = &Local::now().format("%d.%m.%Y").to_string();
 
@@ -107,7 +214,10 @@ pub mod get_financial_data {
         }
 
         pub fn market_cap(&self) -> String {
-            key_value_from_summary(&self.url, "td[data-test='MARKET_CAP-value']")
+            key_value_from_summary(&self.url, "td[data-test='MARKET_CAP-value']").unwrap_or_else(|| {
+                println!("Error happened when trying to get 'Market Cap', so this will be displayed as 0.0.");
+                "0.0".to_string()
+            })
         }
 
         pub fn debt_equity_ratio(&self) -> f64 {
@@ -191,11 +301,96 @@ pub mod get_financial_data {
                 &self.url, "Book Value Per Share", "Book Value Per Share (mrq)"
             ).unwrap().parse::<f64>().unwrap()
         }
+
+        pub fn levered_free_cash_flow(&self) -> String {
+            key_value_from_statistics(
+                &self.url, 
+                "Levered Free Cash Flow", 
+                "Levered Free Cash Flow (ttm)"
+            ).unwrap()
+        }
+
+        pub fn shares_outstanding(&self) -> String {
+            key_value_from_statistics(
+                &self.url, 
+                "Shares Outstanding", 
+                "Shares Outstanding"
+            ).unwrap()
+        }
+
+        pub fn sector(&self) -> String {
+            key_value_from_profile(&self.url, "Sector(s)")
+        }
+
+        pub fn industry(&self) -> String {
+            key_value_from_profile(&self.url, "Industry")
+        }
+
+        pub fn country(&self) -> String {
+            let selector = scraper::Selector::parse("div[data-test='qsp-profile'] p").unwrap();
+
+            // The address ends with the country, followed by phone number and website.
+            let country = self.url.select(&selector)
+                .next()
+                .map(|address| address.inner_html())
+                .and_then(|address| address.split("<br>")
+                    .map(|line| line.trim().to_string())
+                    .filter(|line| !line.is_empty() && !line.contains('<') && !line.chars().any(|c| c.is_ascii_digit()))
+                    .last());
+
+            classification_or_unknown(country, "Country")
+        }
+
+        pub fn currency(&self) -> String {
+            let selector = scraper::Selector::parse("div[id='quote-header-info'] span").unwrap();
+
+            let currency = self.url.select(&selector)
+                .map(|span| span.inner_html())
+                .find_map(|text| Regex::new(r"Currency in ([A-Z]{3})").unwrap()
+                    .captures(&text)
+                    .map(|captures| captures[1].to_string()));
+
+            classification_or_unknown(currency, "Currency")
+        }
+    }
+
+    fn key_value_from_profile(url: &Html, label: &str) -> String {
+        let selector = scraper::Selector::parse("div[data-test='qsp-profile'] p span").unwrap();
+        let spans: Vec<String> = url.select(&selector).map(|span| span.inner_html()).collect();
+
+        let value = spans.iter()
+            .position(|span| span.trim() == label)
+            .and_then(|i| spans.get(i + 1))
+            .map(|value| value.replace("&amp;", "&"));
+
+        classification_or_unknown(value, label)
+    }
+
+    /// Commas and semicolons separate fields and rows in the file backend, so they're removed.
+    fn classification_or_unknown(value: Option<String>, error_message: &str) -> String {
+        match value {
+            Some(value) if !value.trim().is_empty() => value.replace([',', ';'], "").trim().to_string(),
+            _ => {
+                println!("Error happened when trying to get '{}', so this will be displayed as Unknown.", error_message);
+                "Unknown".to_string()
+            }
+        }
     }
 
-    fn key_value_from_summary(url: &Html, stock_key: &str) -> String {
+    fn key_value_from_summary(url: &Html, stock_key: &str) -> Option<String> {
         let selector = scraper::Selector::parse(stock_key).unwrap();
-        url.select(&selector).next().unwrap().inner_html()
+        url.select(&selector).next().map(|value| value.inner_html())
+    }
+
+    /// Values like "N/A" for the P/E of a company with losses are displayed as 0.0.
+    fn number_or_zero(value: Option<String>, error_message: &str) -> f64 {
+        match value.and_then(|value| value.replace(',', "").parse::<f64>().ok()) {
+            Some(value) => value,
+            None => {
+                println!("Error happened when trying to get '{}', so this will be displayed as 0.0.", error_message);
+                0.0
+            }
+        }
     }
 
     fn key_value_from_statistics(
@@ -206,19 +401,21 @@ pub mod get_financial_data {
             Err(_) => return Err(YahooError::ParseError { value: name_to_scrape.to_string() })
         };
 
+        // Only the first matching row is read, so a value of "N/A" isn't replaced by a number of 
+        // a later row.
         let html: String = url.select(&selector)
                             .map(|x| x.inner_html())
-                            .filter(|data| data.contains(name_to_scrape))
-                            .collect();
+                            .find(|data| data.contains(name_to_scrape))
+                            .unwrap_or_default();
 
-        let val = match Regex::new(r"\d+\.\d?.[A-Z]?") {
+        let val = match Regex::new(r"-?\d+\.\d*[kKMBT]?") {
             Ok(val) => val.captures(&html),
             Err(_) => return Err(YahooError::RegexError { value: name_to_scrape.to_string() })
         };
 
         let value = match val {
-            Some(n) => n[0].to_string(),
-            None => {
+            Some(n) if !html.contains("N/A") => n[0].to_string(),
+            _ => {
                 println!("Error happened when trying to get '{}', so this will be displayed as 0.0.", error_message);
                 "0.0".to_string()
             },
@@ -227,47 +424,136 @@ pub mod get_financial_data {
         Ok(value)
     }
 
-    pub async fn calc_historical_price(symbol: &str, day: i32, month: i32, year: i32) -> f64 {
-        let start = Utc.with_ymd_and_hms(
-            year, month as u32, day as u32, 0, 0, 0)
-        .unwrap().timestamp();
-        
-        let end = Utc.with_ymd_and_hms(
-            year, month as u32, day as u32, 23, 59, 59)
-        .unwrap().timestamp();
+    fn chart_url(symbol: &str, start: i64, end: i64, interval: &str) -> String {
+        format!(
+            "https://query1.finance.yahoo.com/v8/finance/chart/{}?symbol={}&period1={}&period2={}&interval={}&events=div",
+            symbol, symbol, start, end, interval
+        )
+    }
 
-        let url = format!(
-            "https://query1.finance.yahoo.com/v8/finance/chart/{}?symbol={}&period1={}&period2={}&interval=1d",
-            symbol, symbol, start, end
-        );
+    pub async fn fetch_chart(
+        symbol: &str, start: i64, end: i64, interval: &str
+    ) -> Result<QuoteBlock, YahooError> {
+        let url = chart_url(symbol, start, end, interval);
 
         let data = task::spawn_blocking(|| {
-            let resp = reqwest::blocking::get(url).unwrap();
-            resp.json::<Response>()
-        }).await.unwrap();
-        
-        let price = match data {
-                Ok(price) => price.chart.result
-                    .get(0)
-                    .unwrap()
-                    .indicators
-                    .quote
-                    .get(0)
-                    .unwrap()
-                    .close
-                    .get(0)
-                    .unwrap()
-                    .unwrap(),
-                Err(_) => {
-                    println!("Date is a holiday or a day in which the stock exchange was closed.");
-                    0.0
+            reqwest::blocking::get(url).and_then(|resp| resp.json::<Response>())
+        }).await;
+
+        match data {
+            Ok(Ok(response)) => response.chart.result
+                .into_iter()
+                .next()
+                .ok_or(YahooError::HistoryUnavailable { value: symbol.to_string() }),
+            _ => Err(YahooError::HistoryUnavailable { value: symbol.to_string() })
+        }
+    }
+
+    /// Bars of the trading session on the given date. The date is interpreted in the 
+    /// time zone of the exchange, which is why a day before and after is requested as well.
+    pub async fn session_bars(
+        symbol: &str, date: NaiveDate, interval: &str
+    ) -> Result<(Vec<Bar>, Meta), YahooError> {
+        let start = (date - Duration::days(1)).and_hms_opt(0, 0, 0).unwrap().timestamp();
+        let end = (date + Duration::days(1)).and_hms_opt(23, 59, 59).unwrap().timestamp();
+
+        let block = fetch_chart(symbol, start, end, interval).await?;
+
+        let bars = block.bars()
+            .into_iter()
+            .filter(|bar| bar.time.date_naive() == date)
+            .collect();
+
+        Ok((bars, block.meta))
+    }
+
+    pub async fn calc_historical_price(symbol: &str, day: i32, month: i32, year: i32) -> f64 {
+        let date = NaiveDate::from_ymd_opt(year, month as u32, day as u32).unwrap();
+
+        match session_bars(symbol, date, "1d").await {
+            Ok((bars, _)) if !bars.is_empty() => bars[bars.len() - 1].close,
+            _ => {
+                println!("Date is a holiday or a day in which the stock exchange was closed.");
+                0.0
             }
+        }
+    }
+
+    pub async fn historical_prices(
+        symbol: &str, from: NaiveDate, to: NaiveDate
+    ) -> Result<Vec<(NaiveDate, f64)>, YahooError> {
+        let start = (from - Duration::days(1)).and_hms_opt(0, 0, 0).unwrap().timestamp();
+        let end = (to + Duration::days(1)).and_hms_opt(23, 59, 59).unwrap().timestamp();
+
+        let block = fetch_chart(symbol, start, end, "1d").await?;
+
+        let prices = block.bars()
+            .into_iter()
+            .map(|bar| (bar.time.date_naive(), bar.close))
+            .filter(|(date, _)| *date >= from && *date <= to)
+            .collect();
+
+        Ok(prices)
+    }
+
+    /// Dividends paid per share with their ex-dates in the time zone of the exchange.
+    pub async fn dividend_history(
+        symbol: &str, from: NaiveDate, to: NaiveDate
+    ) -> Result<Vec<(NaiveDate, f64)>, YahooError> {
+        let start = (from - Duration::days(1)).and_hms_opt(0, 0, 0).unwrap().timestamp();
+        let end = (to + Duration::days(1)).and_hms_opt(23, 59, 59).unwrap().timestamp();
+
+        let block = fetch_chart(symbol, start, end, "1d").await?;
+
+        let mut dividends: Vec<(NaiveDate, f64)> = block.events.dividends
+            .values()
+            .filter_map(|dividend| {
+                let date = block.meta.time(dividend.date)?.date_naive();
+                Some((date, dividend.amount))
+            })
+            .filter(|(date, _)| *date >= from && *date <= to)
+            .collect();
+        dividends.sort_by_key(|(date, _)| *date);
+
+        Ok(dividends)
+    }
+
+    /// How many days back Yahoo provides data for an intraday interval.
+    pub fn intraday_limit(interval: &str) -> Option<i64> {
+        match interval {
+            "1m" => Some(29),
+            "5m" | "15m" => Some(59),
+            "1h" => Some(729),
+            _ => None
+        }
+    }
+
+    const DATE_ERROR: &str = "Date could not be read. Date needs to be in DMY(01.01.2020) format or NUMBER.days/weeks/months/years.ago";
+
+    pub fn parse_naive_date(date: String) -> Result<NaiveDate, &'static str> {
+        let splitted_date = split_date(date)?;
+
+        NaiveDate::from_ymd_opt(
+            splitted_date[2], 
+            splitted_date[1] as u32, 
+            splitted_date[0] as u32
+        ).ok_or(DATE_ERROR)
+    }
+
+    /// Start and end of a period, the end defaults to today.
+    pub fn parse_period(from: String, to: Option<String>) -> Result<(NaiveDate, NaiveDate), &'static str> {
+        let from = parse_naive_date(from)?;
+
+        let to = match to {
+            Some(to) => parse_naive_date(to)?,
+            None => Local::now().date_naive()
         };
 
-        price
+        Ok((from, to))
     }
 
-    pub fn split_date(mut date: String) -> Vec<i32> {
+    /// Day, month and year of a date like 01.01.2020 or 1.year.ago.
+    pub fn split_date(mut date: String) -> Result<Vec<i32>, &'static str> {
         if date.contains("day") || 
             date.contains("week") ||
             date.contains("month") ||
@@ -276,14 +562,17 @@ pub mod get_financial_data {
         }
             
         let splitted_date: Vec<i32> = date.split(".")
-            .into_iter()
-            .map(|d| d.parse::<i32>().unwrap())
-            .collect();
-    
-        vec![splitted_date[0], splitted_date[1], splitted_date[2]]
+            .map(|d| d.trim().parse::<i32>())
+            .collect::<Result<Vec<i32>, _>>()
+            .map_err(|_| DATE_ERROR)?;
+
+        match splitted_date[..] {
+            [day, month, year] => Ok(vec![day, month, year]),
+            _ => Err(DATE_ERROR)
+        }
     }
     
-    pub fn format_date(mut splitted_date: Vec<i32>) -> Result<chrono::NaiveDate, &'static str> {
+    pub fn format_date(mut splitted_date: Vec<i32>, locale: Locale) -> Result<chrono::NaiveDate, &'static str> {
         let mut parsed_date = NaiveDate::from_ymd_opt(
             splitted_date[2], 
             splitted_date[1].try_into().unwrap(), 
@@ -298,11 +587,11 @@ pub mod get_financial_data {
             parsed_date.format("%A").to_string() == "Sunday" {
     
             if parsed_date.format("%A").to_string() == "Saturday" {
-                splitted_date[0] = splitted_date[0] + 2;
-                println!("It's a Saturday so we'll take Monday.");
+                splitted_date[0] += 2;
+                println!("{}", locale.label("It's a Saturday so we'll take Monday."));
             } else if parsed_date.format("%A").to_string() == "Sunday" {
-                splitted_date[0] = splitted_date[0] + 1;
-                println!("It's a Sunday so we'll take Monday.");
+                splitted_date[0] += 1;
+                println!("{}", locale.label("It's a Sunday so we'll take Monday."));
             }
     
             parsed_date = NaiveDate::from_ymd_opt(
@@ -315,10 +604,16 @@ pub mod get_financial_data {
         Ok(parsed_date)
     }
     
-    pub async fn print_history_price(symbol: String, splitted_date: Vec<i32>, current_price: f64) {
+    pub async fn print_history_price(
+        symbol: String, splitted_date: Vec<i32>, current_price: f64, interval: String, locale: Locale
+    ) {
+        if interval != "1d" {
+            return print_intraday_price(symbol, splitted_date, current_price, interval, locale).await
+        }
+
         let splitted_date_cloned = splitted_date.clone();
     
-        match format_date(splitted_date_cloned) {
+        match format_date(splitted_date_cloned, locale) {
             Ok(parsed_date) => {
                 let stock_name_cloned = symbol.clone();
         
@@ -329,7 +624,7 @@ pub mod get_financial_data {
                 match price_history {
                     Ok(price) => {
                         if price == 0.0 {
-                            return println!("Please take another day.")
+                            return println!("{}", locale.label("Please take another day."))
                         }
     
                         let date = splitted_date[0].to_string() 
@@ -338,22 +633,77 @@ pub mod get_financial_data {
                                             + "." + 
                                             splitted_date[2].to_string().as_str();
             
-                        println!("Stock: {}", stock_name_cloned.to_uppercase());
-                        println!("Price since last update: {}", current_price);
-                        
-                        let percentage = (current_price / price) * 100.0;
-                        println!("Date {}, {}", date, parsed_date.format("%A"));
-                        println!("Price: {:.2}", price);
-                        if percentage > 100.0 {
-                            println!("Increase until today: {:.2}%", percentage - 100.0);
-                        } else {
-                            println!("Decrease until today: {:.2}%", percentage - 100.0);
-                        }
+                        println!("{}: {}", locale.label("Stock"), stock_name_cloned.to_uppercase());
+                        println!("{}: {}", locale.label("Price since last update"), locale.value(&current_price.to_string()));
+                        println!("{} {}, {}", locale.label("Date"), date, locale.label(&parsed_date.format("%A").to_string()));
+                        print_change(price, current_price, locale);
                     },
                     Err(e) => println!("Error happened: {}", e)
                 }
             },
-            Err(e) => println!("{}", e)
+            Err(e) => println!("{}", locale.label(e))
+        }
+    }
+
+    async fn print_intraday_price(
+        symbol: String, splitted_date: Vec<i32>, current_price: f64, interval: String, locale: Locale
+    ) {
+        let limit = match intraday_limit(interval.as_str()) {
+            Some(limit) => limit,
+            None => return println!("{}", locale.fill("Interval {} is not supported. Use 1m, 5m, 15m, 1h or 1d.", &[&interval]))
+        };
+
+        let date = match format_date(splitted_date, locale) {
+            Ok(date) => date,
+            Err(e) => return println!("{}", locale.label(e))
+        };
+
+        if (Local::now().date_naive() - date).num_days() > limit {
+            return println!(
+                "{}", 
+                locale.fill("Prices in {} intervals are only available for the last {} days.", &[&interval, &limit.to_string()])
+            )
+        }
+
+        let (bars, meta) = match session_bars(symbol.as_str(), date, interval.as_str()).await {
+            Ok(session) => session,
+            Err(e) => return println!("{}", e)
+        };
+
+        if bars.is_empty() {
+            println!("{}", locale.label("Date is a holiday or a day in which the stock exchange was closed."));
+            return println!("{}", locale.label("Please take another day."))
+        }
+
+        println!("{}: {}", locale.label("Stock"), symbol.to_uppercase());
+        println!("{}: {}", locale.label("Price since last update"), locale.value(&current_price.to_string()));
+        println!("{} {}, {}", locale.label("Date"), date.format("%d.%m.%Y"), locale.label(&date.format("%A").to_string()));
+        println!("{} {} ({})", locale.label("Times in"), meta.exchange_timezone_name, meta.timezone);
+
+        let rows: Vec<Vec<String>> = bars.iter()
+            .map(|bar| vec![
+                bar.time.format("%H:%M").to_string(),
+                locale.number(bar.open, 2),
+                locale.number(bar.high, 2),
+                locale.number(bar.low, 2),
+                locale.number(bar.close, 2),
+                locale.number(bar.volume as f64, 0),
+            ])
+            .collect();
+
+        let headers = ["Time", "Open", "High", "Low", "Close", "Volume"].map(|header| locale.label(header));
+        print_table(&headers, &rows);
+
+        print_change(bars[bars.len() - 1].close, current_price, locale);
+    }
+
+    fn print_change(price: f64, current_price: f64, locale: Locale) {
+        let percentage = (current_price / price) * 100.0;
+        println!("{}: {}", locale.label("Price"), locale.number(price, 2));
+        if percentage > 100.0 {
+            println!("{}: {}%", locale.label("Increase until today"), locale.number(percentage - 100.0, 2));
+        } else {
+            println!("{}: {}%", locale.label("Decrease until today"), locale.number(percentage - 100.0, 2));
         }
     }
-}
+}
\ No newline at end of file
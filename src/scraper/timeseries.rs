@@ -0,0 +1,115 @@
+pub mod timeseries {
+    use std::collections::HashMap;
+
+    use chrono::Local;
+    use serde::Deserialize;
+    use tokio::task;
+
+    use crate::errors::error_handler::error_handler::YahooError;
+    use crate::statements::statements::statements::{Frequency, Kind, StatementRow};
+
+    #[derive(Deserialize, Debug)]
+    struct Response {
+        timeseries: TimeseriesResult,
+    }
+
+    #[derive(Deserialize, Debug)]
+    struct TimeseriesResult {
+        #[serde(default)]
+        result: Vec<Series>,
+    }
+
+    /// The values of a series are found under the name of its type, e.g. "annualTotalRevenue".
+    #[derive(Deserialize, Debug)]
+    struct Series {
+        meta: SeriesMeta,
+        #[serde(flatten)]
+        data: HashMap<String, serde_json::Value>,
+    }
+
+    #[derive(Deserialize, Debug)]
+    struct SeriesMeta {
+        #[serde(rename = "type")]
+        kind: Vec<String>,
+    }
+
+    #[derive(Deserialize, Debug)]
+    struct DataPoint {
+        #[serde(rename = "asOfDate")]
+        as_of_date: String,
+        #[serde(rename = "reportedValue")]
+        reported_value: ReportedValue,
+    }
+
+    #[derive(Deserialize, Debug)]
+    struct ReportedValue {
+        raw: f64,
+    }
+
+    /// Yahoo only returns the statements of the last four to five periods.
+    const FIRST_PERIOD: i64 = 493590046;
+
+    fn timeseries_url(symbol: &str, kind: Kind, frequency: Frequency) -> String {
+        let types: Vec<String> = kind.items().iter()
+            .map(|(item, _)| format!("{}{}", frequency.as_str(), item))
+            .collect();
+
+        format!(
+            "https://query1.finance.yahoo.com/ws/fundamentals-timeseries/v1/finance/timeseries/{}?symbol={}&type={}&period1={}&period2={}",
+            symbol, symbol, types.join(","), FIRST_PERIOD, Local::now().timestamp()
+        )
+    }
+
+    pub async fn fetch_statement(
+        symbol: &str, kind: Kind, frequency: Frequency
+    ) -> Result<Vec<StatementRow>, YahooError> {
+        let url = timeseries_url(symbol, kind, frequency);
+
+        let data = task::spawn_blocking(|| {
+            reqwest::blocking::get(url).and_then(|resp| resp.json::<Response>())
+        }).await;
+
+        let series = match data {
+            Ok(Ok(response)) => response.timeseries.result,
+            _ => return Err(YahooError::StatementUnavailable { value: symbol.to_string() })
+        };
+
+        let rows = statement_rows(series, symbol, kind, frequency);
+
+        if rows.is_empty() {
+            return Err(YahooError::StatementUnavailable { value: symbol.to_string() })
+        }
+
+        Ok(rows)
+    }
+
+    fn statement_rows(series: Vec<Series>, symbol: &str, kind: Kind, frequency: Frequency) -> Vec<StatementRow> {
+        let mut rows = Vec::new();
+
+        for series in series {
+            let series_type = match series.meta.kind.first() {
+                Some(series_type) => series_type,
+                None => continue
+            };
+
+            let item = series_type.trim_start_matches(frequency.as_str()).to_string();
+            let points: Vec<Option<DataPoint>> = match series.data.get(series_type) {
+                Some(points) => serde_json::from_value(points.clone()).unwrap_or_default(),
+                None => continue
+            };
+
+            for point in points.into_iter().flatten() {
+                rows.push(StatementRow {
+                    symbol: symbol.to_string(),
+                    kind,
+                    frequency,
+                    period: point.as_of_date,
+                    item: item.clone(),
+                    value: point.reported_value.raw,
+                });
+            }
+        }
+
+        rows
+    }
+}
\ No newline at end of file
@@ -0,0 +1,4 @@
+pub mod statements;
+
+pub mod ratios;
+pub mod scores;
\ No newline at end of file
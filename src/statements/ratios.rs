@@ -0,0 +1,116 @@
+pub mod ratios {
+    use std::collections::BTreeSet;
+
+    use crate::database::queries::queries::format_amount;
+    use crate::output::locale::locale::Locale;
+    use crate::output::table::table::print_table;
+    use crate::statements::statements::statements::{
+        StatementRow, balance_sheets, cash_flow_statements, income_statements
+    };
+
+    /// Ratios and values of a fiscal period, named like the terms of `info`.
+    #[derive(Debug, Clone, Default)]
+    pub struct Ratios {
+        pub period: String,
+        pub current_ratio: Option<f64>,
+        pub assets: Option<f64>,
+        pub liabilities: Option<f64>,
+        pub equity: Option<f64>,
+        pub debt_equity_ratio: Option<f64>,
+        pub return_on_equity: Option<f64>,
+        pub return_on_assets: Option<f64>,
+        pub cash_from_operating_activities: Option<f64>,
+        pub cash_from_investing_activities: Option<f64>,
+        pub cash_from_financing_activities: Option<f64>,
+    }
+
+    impl Ratios {
+        /// Term and value of every ratio, formatted in the locale.
+        pub fn formatted(&self, locale: Locale) -> Vec<(&'static str, String)> {
+            let amount = |value: Option<f64>| value.map(|value| locale.value(&format_amount(value))).unwrap_or_else(|| "-".to_string());
+            let ratio = |value: Option<f64>| value.map(|value| locale.number(value, 2)).unwrap_or_else(|| "-".to_string());
+            let percent = |value: Option<f64>| value.map(|value| format!("{}%", locale.number(value, 2))).unwrap_or_else(|| "-".to_string());
+
+            vec![
+                ("current_ratio", ratio(self.current_ratio)),
+                ("assets", amount(self.assets)),
+                ("liabilities", amount(self.liabilities)),
+                ("equity", amount(self.equity)),
+                ("debt_equity_ratio", percent(self.debt_equity_ratio)),
+                ("return_on_equity", percent(self.return_on_equity)),
+                ("return_on_assets", percent(self.return_on_assets)),
+                ("cash_from_operating_activities", amount(self.cash_from_operating_activities)),
+                ("cash_from_investing_activities", amount(self.cash_from_investing_activities)),
+                ("cash_from_financing_activities", amount(self.cash_from_financing_activities)),
+            ]
+        }
+    }
+
+    fn divide(numerator: Option<f64>, denominator: Option<f64>) -> Option<f64> {
+        match (numerator, denominator) {
+            (Some(numerator), Some(denominator)) if denominator != 0.0 => Some(numerator / denominator),
+            _ => None
+        }
+    }
+
+    /// Ratios per period, oldest period first. Debt/equity and returns are in percent.
+    pub fn ratios(rows: &[StatementRow]) -> Vec<Ratios> {
+        let incomes = income_statements(rows);
+        let balances = balance_sheets(rows);
+        let cash_flows = cash_flow_statements(rows);
+
+        let periods: BTreeSet<String> = balances.iter().map(|balance| balance.period.clone())
+            .chain(cash_flows.iter().map(|cash_flow| cash_flow.period.clone()))
+            .collect();
+
+        periods.into_iter()
+            .map(|period| {
+                let income = incomes.iter().find(|income| income.period == period).cloned().unwrap_or_default();
+                let balance = balances.iter().find(|balance| balance.period == period).cloned().unwrap_or_default();
+                let cash_flow = cash_flows.iter().find(|cash_flow| cash_flow.period == period).cloned().unwrap_or_default();
+
+                Ratios {
+                    period,
+                    current_ratio: divide(balance.current_assets, balance.current_liabilities),
+                    assets: balance.total_assets,
+                    liabilities: balance.total_liabilities,
+                    equity: balance.stockholders_equity,
+                    debt_equity_ratio: divide(balance.total_debt, balance.stockholders_equity).map(|ratio| ratio * 100.0),
+                    return_on_equity: divide(income.net_income, balance.stockholders_equity).map(|ratio| ratio * 100.0),
+                    return_on_assets: divide(income.net_income, balance.total_assets).map(|ratio| ratio * 100.0),
+                    cash_from_operating_activities: cash_flow.operating_cash_flow,
+                    cash_from_investing_activities: cash_flow.investing_cash_flow,
+                    cash_from_financing_activities: cash_flow.financing_cash_flow,
+                }
+            })
+            .collect()
+    }
+
+    pub fn print_ratios(rows: &[StatementRow], locale: Locale) {
+        let ratios = ratios(rows);
+
+        if ratios.is_empty() {
+            return println!(
+                "{}", 
+                locale.label("No financial statements are saved. Run 'financials SYMBOL --statement balance' (and income, cashflow) to compute ratios.")
+            )
+        }
+
+        let mut headers = vec![locale.label("Term")];
+        headers.extend(ratios.iter().rev().map(|ratios| ratios.period.as_str()));
+
+        let columns: Vec<Vec<(&str, String)>> = ratios.iter().rev().map(|ratios| ratios.formatted(locale)).collect();
+
+        let table: Vec<Vec<String>> = columns[0].iter()
+            .enumerate()
+            .map(|(i, (term, _))| {
+                let mut row = vec![term.to_string()];
+                row.extend(columns.iter().map(|column| column[i].1.clone()));
+                row
+            })
+            .collect();
+
+        println!("{}", locale.label("Computed from the annual financial statements (run 'info TERM' for an explanation):"));
+        print_table(&headers, &table);
+    }
+}
@@ -0,0 +1,239 @@
+pub mod scores {
+    use crate::database::queries::queries::format_amount;
+    use crate::output::table::table::print_table;
+    use crate::statements::statements::statements::{
+        BalanceSheet, CashFlowStatement, IncomeStatement, StatementRow, 
+        balance_sheets, cash_flow_statements, income_statements
+    };
+
+    pub struct Criterion {
+        pub name: &'static str,
+        /// None if an input is missing in the statements.
+        pub passed: Option<bool>,
+        pub inputs: String,
+    }
+
+    pub struct Component {
+        pub name: &'static str,
+        pub weight: f64,
+        pub ratio: Option<f64>,
+        pub inputs: String,
+    }
+
+    /// Statements of a fiscal year.
+    pub struct Year {
+        pub income: IncomeStatement,
+        pub balance: BalanceSheet,
+        pub cash_flow: CashFlowStatement,
+    }
+
+    /// The latest and the year before, matched by the periods of the balance sheets.
+    pub fn last_two_years(rows: &[StatementRow]) -> Option<(Year, Year)> {
+        let balances = balance_sheets(rows);
+        let incomes = income_statements(rows);
+        let cash_flows = cash_flow_statements(rows);
+
+        let year = |balance: &BalanceSheet| Year {
+            income: incomes.iter().find(|income| income.period == balance.period).cloned().unwrap_or_default(),
+            balance: balance.clone(),
+            cash_flow: cash_flows.iter().find(|cash_flow| cash_flow.period == balance.period).cloned().unwrap_or_default(),
+        };
+
+        match balances.as_slice() {
+            [.., prior, current] => Some((year(current), year(prior))),
+            _ => None
+        }
+    }
+
+    fn ratio(numerator: Option<f64>, denominator: Option<f64>) -> Option<f64> {
+        match (numerator, denominator) {
+            (Some(numerator), Some(denominator)) if denominator != 0.0 => Some(numerator / denominator),
+            _ => None
+        }
+    }
+
+    fn value(value: Option<f64>) -> String {
+        value.map(format_amount).unwrap_or_else(|| "-".to_string())
+    }
+
+    fn number(value: Option<f64>) -> String {
+        value.map(|value| format!("{:.4}", value)).unwrap_or_else(|| "-".to_string())
+    }
+
+    fn compare(current: Option<f64>, prior: Option<f64>, test: fn(f64, f64) -> bool) -> Option<bool> {
+        Some(test(current?, prior?))
+    }
+
+    /// The nine criteria of the Piotroski F-score. The return on assets uses the total 
+    /// assets at the end of the year, since only two years are compared.
+    pub fn piotroski(current: &Year, prior: &Year) -> Vec<Criterion> {
+        let roa = |year: &Year| ratio(year.income.net_income, year.balance.total_assets);
+        let leverage = |year: &Year| ratio(year.balance.long_term_debt, year.balance.total_assets);
+        let current_ratio = |year: &Year| ratio(year.balance.current_assets, year.balance.current_liabilities);
+        let gross_margin = |year: &Year| ratio(year.income.gross_profit, year.income.total_revenue);
+        let asset_turnover = |year: &Year| ratio(year.income.total_revenue, year.balance.total_assets);
+        let shares = |year: &Year| year.balance.shares_outstanding.or(year.income.diluted_average_shares);
+
+        let operating_cash_flow = current.cash_flow.operating_cash_flow;
+
+        vec![
+            Criterion {
+                name: "Positive return on assets",
+                passed: roa(current).map(|roa| roa > 0.0),
+                inputs: format!("net income {} / total assets {} = {}", 
+                    value(current.income.net_income), value(current.balance.total_assets), number(roa(current))),
+            },
+            Criterion {
+                name: "Positive operating cash flow",
+                passed: operating_cash_flow.map(|cash_flow| cash_flow > 0.0),
+                inputs: format!("operating cash flow {}", value(operating_cash_flow)),
+            },
+            Criterion {
+                name: "Higher return on assets",
+                passed: compare(roa(current), roa(prior), |current, prior| current > prior),
+                inputs: format!("{} vs. {}", number(roa(current)), number(roa(prior))),
+            },
+            Criterion {
+                name: "Operating cash flow above net income",
+                passed: compare(operating_cash_flow, current.income.net_income, |cash_flow, income| cash_flow > income),
+                inputs: format!("{} vs. {}", value(operating_cash_flow), value(current.income.net_income)),
+            },
+            Criterion {
+                name: "Lower long term debt to assets",
+                passed: compare(leverage(current), leverage(prior), |current, prior| current < prior),
+                inputs: format!("{} vs. {}", number(leverage(current)), number(leverage(prior))),
+            },
+            Criterion {
+                name: "Higher current ratio",
+                passed: compare(current_ratio(current), current_ratio(prior), |current, prior| current > prior),
+                inputs: format!("{} vs. {}", number(current_ratio(current)), number(current_ratio(prior))),
+            },
+            Criterion {
+                name: "No new shares issued",
+                passed: compare(shares(current), shares(prior), |current, prior| current <= prior),
+                inputs: format!("{} vs. {}", value(shares(current)), value(shares(prior))),
+            },
+            Criterion {
+                name: "Higher gross margin",
+                passed: compare(gross_margin(current), gross_margin(prior), |current, prior| current > prior),
+                inputs: format!("{} vs. {}", number(gross_margin(current)), number(gross_margin(prior))),
+            },
+            Criterion {
+                name: "Higher asset turnover",
+                passed: compare(asset_turnover(current), asset_turnover(prior), |current, prior| current > prior),
+                inputs: format!("{} vs. {}", number(asset_turnover(current)), number(asset_turnover(prior))),
+            },
+        ]
+    }
+
+    /// Components of the Altman Z-score with their weight, and the score if no input is missing.
+    /// The market value of equity is the current price times the shares outstanding.
+    pub fn altman(year: &Year, price: Option<f64>) -> (Vec<Component>, Option<f64>) {
+        let total_assets = year.balance.total_assets;
+        let working_capital = year.balance.working_capital.or_else(|| 
+            Some(year.balance.current_assets? - year.balance.current_liabilities?)
+        );
+        let market_value = price.and_then(|price| year.balance.shares_outstanding.map(|shares| price * shares));
+
+        let components = vec![
+            Component {
+                name: "Working capital / total assets",
+                weight: 1.2,
+                ratio: ratio(working_capital, total_assets),
+                inputs: format!("{} / {}", value(working_capital), value(total_assets)),
+            },
+            Component {
+                name: "Retained earnings / total assets",
+                weight: 1.4,
+                ratio: ratio(year.balance.retained_earnings, total_assets),
+                inputs: format!("{} / {}", value(year.balance.retained_earnings), value(total_assets)),
+            },
+            Component {
+                name: "EBIT / total assets",
+                weight: 3.3,
+                ratio: ratio(year.income.ebit, total_assets),
+                inputs: format!("{} / {}", value(year.income.ebit), value(total_assets)),
+            },
+            Component {
+                name: "Market value of equity / total liabilities",
+                weight: 0.6,
+                ratio: ratio(market_value, year.balance.total_liabilities),
+                inputs: format!("{} / {}", value(market_value), value(year.balance.total_liabilities)),
+            },
+            Component {
+                name: "Sales / total assets",
+                weight: 1.0,
+                ratio: ratio(year.income.total_revenue, total_assets),
+                inputs: format!("{} / {}", value(year.income.total_revenue), value(total_assets)),
+            },
+        ];
+
+        let score = components.iter()
+            .map(|component| component.ratio.map(|ratio| component.weight * ratio))
+            .sum::<Option<f64>>();
+
+        (components, score)
+    }
+
+    pub fn print_scores(symbol: &str, rows: &[StatementRow], price: Option<f64>) {
+        let (current, prior) = match last_two_years(rows) {
+            Some(years) => years,
+            None => return println!("Two annual balance sheets are needed to calculate the scores of {}.", symbol.to_uppercase())
+        };
+
+        let criteria = piotroski(&current, &prior);
+        let score = criteria.iter().filter(|criterion| criterion.passed == Some(true)).count();
+        let missing = criteria.iter().filter(|criterion| criterion.passed.is_none()).count();
+
+        println!("Piotroski F-score of {} ({} vs. {})", symbol.to_uppercase(), current.balance.period, prior.balance.period);
+
+        let table: Vec<Vec<String>> = criteria.iter()
+            .map(|criterion| vec![
+                criterion.name.to_string(),
+                match criterion.passed {
+                    Some(true) => "pass",
+                    Some(false) => "fail",
+                    None => "n/a"
+                }.to_string(),
+                criterion.inputs.clone(),
+            ])
+            .collect();
+
+        print_table(&["Criterion", "Result", "Inputs"], &table);
+        println!("F-score: {}/9", score);
+        if missing > 0 {
+            println!("{} criteria couldn't be checked because of missing values and count as failed.", missing);
+        }
+
+        println!();
+        println!("Altman Z-score of {} ({})", symbol.to_uppercase(), current.balance.period);
+
+        let (components, z_score) = altman(&current, price);
+        let table: Vec<Vec<String>> = components.iter()
+            .map(|component| vec![
+                component.name.to_string(),
+                format!("{:.1}", component.weight),
+                number(component.ratio),
+                component.inputs.clone(),
+            ])
+            .collect();
+
+        print_table(&["Component", "Weight", "Ratio", "Inputs"], &table);
+
+        match z_score {
+            Some(z_score) => {
+                let zone = if z_score > 2.99 {
+                    "safe zone"
+                } else if z_score >= 1.81 {
+                    "grey zone"
+                } else {
+                    "distress zone"
+                };
+
+                println!("Z-score: {:.2} ({})", z_score, zone)
+            },
+            None if price.is_none() => println!("Z-score: - (add the stock to use its current price for the market value)"),
+            None => println!("Z-score: - (values are missing in the statements)")
+        }
+    }
+}
@@ -0,0 +1,317 @@
+pub mod statements {
+    use std::collections::{BTreeMap, HashMap};
+
+    use crate::database::queries::queries::format_amount;
+    use crate::output::table::table::print_table;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Kind {
+        Income,
+        Balance,
+        CashFlow,
+    }
+
+    impl Kind {
+        pub fn parse(value: &str) -> Option<Kind> {
+            match value.to_lowercase().as_str() {
+                "income" => Some(Kind::Income),
+                "balance" => Some(Kind::Balance),
+                "cashflow" | "cash-flow" => Some(Kind::CashFlow),
+                _ => None
+            }
+        }
+
+        pub fn as_str(&self) -> &'static str {
+            match self {
+                Kind::Income => "income",
+                Kind::Balance => "balance",
+                Kind::CashFlow => "cashflow",
+            }
+        }
+
+        pub fn title(&self) -> &'static str {
+            match self {
+                Kind::Income => "Income Statement",
+                Kind::Balance => "Balance Sheet",
+                Kind::CashFlow => "Cash Flow Statement",
+            }
+        }
+
+        /// Items of the statement as named by Yahoo's fundamentals timeseries, with their label.
+        pub fn items(&self) -> &'static [(&'static str, &'static str)] {
+            match self {
+                Kind::Income => &[
+                    ("TotalRevenue", "Total Revenue"),
+                    ("CostOfRevenue", "Cost of Revenue"),
+                    ("GrossProfit", "Gross Profit"),
+                    ("OperatingExpense", "Operating Expense"),
+                    ("OperatingIncome", "Operating Income"),
+                    ("EBIT", "EBIT"),
+                    ("InterestExpense", "Interest Expense"),
+                    ("PretaxIncome", "Pretax Income"),
+                    ("TaxProvision", "Tax Provision"),
+                    ("NetIncome", "Net Income"),
+                    ("DilutedEPS", "Diluted EPS"),
+                    ("DilutedAverageShares", "Diluted Average Shares"),
+                ],
+                Kind::Balance => &[
+                    ("TotalAssets", "Total Assets"),
+                    ("CurrentAssets", "Current Assets"),
+                    ("CashAndCashEquivalents", "Cash and Cash Equivalents"),
+                    ("TotalLiabilitiesNetMinorityInterest", "Total Liabilities"),
+                    ("CurrentLiabilities", "Current Liabilities"),
+                    ("LongTermDebt", "Long Term Debt"),
+                    ("TotalDebt", "Total Debt"),
+                    ("StockholdersEquity", "Stockholders' Equity"),
+                    ("RetainedEarnings", "Retained Earnings"),
+                    ("WorkingCapital", "Working Capital"),
+                    ("OrdinarySharesNumber", "Shares Outstanding"),
+                ],
+                Kind::CashFlow => &[
+                    ("OperatingCashFlow", "Cash from Operating Activities"),
+                    ("InvestingCashFlow", "Cash from Investing Activities"),
+                    ("FinancingCashFlow", "Cash from Financing Activities"),
+                    ("CapitalExpenditure", "Capital Expenditure"),
+                    ("FreeCashFlow", "Free Cash Flow"),
+                    ("CashDividendsPaid", "Dividends Paid"),
+                    ("RepurchaseOfCapitalStock", "Share Repurchases"),
+                ],
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Frequency {
+        Annual,
+        Quarterly,
+    }
+
+    impl Frequency {
+        pub fn parse(value: &str) -> Option<Frequency> {
+            match value {
+                "annual" => Some(Frequency::Annual),
+                "quarterly" => Some(Frequency::Quarterly),
+                _ => None
+            }
+        }
+
+        pub fn as_str(&self) -> &'static str {
+            match self {
+                Frequency::Annual => "annual",
+                Frequency::Quarterly => "quarterly",
+            }
+        }
+    }
+
+    /// One value of a statement, the way statements are stored.
+    #[derive(Debug, Clone)]
+    pub struct StatementRow {
+        pub symbol: String,
+        pub kind: Kind,
+        pub frequency: Frequency,
+        /// End of the fiscal period as YYYY-MM-DD.
+        pub period: String,
+        pub item: String,
+        pub value: f64,
+    }
+
+    #[derive(Debug, Clone, Default)]
+    pub struct IncomeStatement {
+        pub period: String,
+        pub total_revenue: Option<f64>,
+        pub cost_of_revenue: Option<f64>,
+        pub gross_profit: Option<f64>,
+        pub operating_expense: Option<f64>,
+        pub operating_income: Option<f64>,
+        pub ebit: Option<f64>,
+        pub interest_expense: Option<f64>,
+        pub pretax_income: Option<f64>,
+        pub tax_provision: Option<f64>,
+        pub net_income: Option<f64>,
+        pub diluted_eps: Option<f64>,
+        pub diluted_average_shares: Option<f64>,
+    }
+
+    #[derive(Debug, Clone, Default)]
+    pub struct BalanceSheet {
+        pub period: String,
+        pub total_assets: Option<f64>,
+        pub current_assets: Option<f64>,
+        pub cash_and_cash_equivalents: Option<f64>,
+        pub total_liabilities: Option<f64>,
+        pub current_liabilities: Option<f64>,
+        pub long_term_debt: Option<f64>,
+        pub total_debt: Option<f64>,
+        pub stockholders_equity: Option<f64>,
+        pub retained_earnings: Option<f64>,
+        pub working_capital: Option<f64>,
+        pub shares_outstanding: Option<f64>,
+    }
+
+    #[derive(Debug, Clone, Default)]
+    pub struct CashFlowStatement {
+        pub period: String,
+        pub operating_cash_flow: Option<f64>,
+        pub investing_cash_flow: Option<f64>,
+        pub financing_cash_flow: Option<f64>,
+        pub capital_expenditure: Option<f64>,
+        pub free_cash_flow: Option<f64>,
+        pub dividends_paid: Option<f64>,
+        pub share_repurchases: Option<f64>,
+    }
+
+    impl IncomeStatement {
+        fn from_values(period: String, values: &HashMap<String, f64>) -> IncomeStatement {
+            let value = |item: &str| values.get(item).copied();
+
+            IncomeStatement {
+                period,
+                total_revenue: value("TotalRevenue"),
+                cost_of_revenue: value("CostOfRevenue"),
+                gross_profit: value("GrossProfit"),
+                operating_expense: value("OperatingExpense"),
+                operating_income: value("OperatingIncome"),
+                ebit: value("EBIT"),
+                interest_expense: value("InterestExpense"),
+                pretax_income: value("PretaxIncome"),
+                tax_provision: value("TaxProvision"),
+                net_income: value("NetIncome"),
+                diluted_eps: value("DilutedEPS"),
+                diluted_average_shares: value("DilutedAverageShares"),
+            }
+        }
+
+        /// Values in the order of `Kind::Income.items()`.
+        pub fn values(&self) -> Vec<Option<f64>> {
+            vec![
+                self.total_revenue, self.cost_of_revenue, self.gross_profit, self.operating_expense,
+                self.operating_income, self.ebit, self.interest_expense, self.pretax_income,
+                self.tax_provision, self.net_income, self.diluted_eps, self.diluted_average_shares,
+            ]
+        }
+    }
+
+    impl BalanceSheet {
+        fn from_values(period: String, values: &HashMap<String, f64>) -> BalanceSheet {
+            let value = |item: &str| values.get(item).copied();
+
+            BalanceSheet {
+                period,
+                total_assets: value("TotalAssets"),
+                current_assets: value("CurrentAssets"),
+                cash_and_cash_equivalents: value("CashAndCashEquivalents"),
+                total_liabilities: value("TotalLiabilitiesNetMinorityInterest"),
+                current_liabilities: value("CurrentLiabilities"),
+                long_term_debt: value("LongTermDebt"),
+                total_debt: value("TotalDebt"),
+                stockholders_equity: value("StockholdersEquity"),
+                retained_earnings: value("RetainedEarnings"),
+                working_capital: value("WorkingCapital"),
+                shares_outstanding: value("OrdinarySharesNumber"),
+            }
+        }
+
+        /// Values in the order of `Kind::Balance.items()`.
+        pub fn values(&self) -> Vec<Option<f64>> {
+            vec![
+                self.total_assets, self.current_assets, self.cash_and_cash_equivalents,
+                self.total_liabilities, self.current_liabilities, self.long_term_debt, self.total_debt,
+                self.stockholders_equity, self.retained_earnings, self.working_capital, self.shares_outstanding,
+            ]
+        }
+    }
+
+    impl CashFlowStatement {
+        fn from_values(period: String, values: &HashMap<String, f64>) -> CashFlowStatement {
+            let value = |item: &str| values.get(item).copied();
+
+            CashFlowStatement {
+                period,
+                operating_cash_flow: value("OperatingCashFlow"),
+                investing_cash_flow: value("InvestingCashFlow"),
+                financing_cash_flow: value("FinancingCashFlow"),
+                capital_expenditure: value("CapitalExpenditure"),
+                free_cash_flow: value("FreeCashFlow"),
+                dividends_paid: value("CashDividendsPaid"),
+                share_repurchases: value("RepurchaseOfCapitalStock"),
+            }
+        }
+
+        /// Values in the order of `Kind::CashFlow.items()`.
+        pub fn values(&self) -> Vec<Option<f64>> {
+            vec![
+                self.operating_cash_flow, self.investing_cash_flow, self.financing_cash_flow,
+                self.capital_expenditure, self.free_cash_flow, self.dividends_paid, self.share_repurchases,
+            ]
+        }
+    }
+
+    /// Values of the rows per period, oldest period first.
+    fn periods(rows: &[StatementRow], kind: Kind) -> BTreeMap<String, HashMap<String, f64>> {
+        let mut periods: BTreeMap<String, HashMap<String, f64>> = BTreeMap::new();
+
+        for row in rows.iter().filter(|row| row.kind == kind) {
+            periods.entry(row.period.clone())
+                .or_default()
+                .insert(row.item.clone(), row.value);
+        }
+
+        periods
+    }
+
+    pub fn income_statements(rows: &[StatementRow]) -> Vec<IncomeStatement> {
+        periods(rows, Kind::Income).into_iter()
+            .map(|(period, values)| IncomeStatement::from_values(period, &values))
+            .collect()
+    }
+
+    pub fn balance_sheets(rows: &[StatementRow]) -> Vec<BalanceSheet> {
+        periods(rows, Kind::Balance).into_iter()
+            .map(|(period, values)| BalanceSheet::from_values(period, &values))
+            .collect()
+    }
+
+    pub fn cash_flow_statements(rows: &[StatementRow]) -> Vec<CashFlowStatement> {
+        periods(rows, Kind::CashFlow).into_iter()
+            .map(|(period, values)| CashFlowStatement::from_values(period, &values))
+            .collect()
+    }
+
+    /// Prints the statement with the latest period first.
+    pub fn print_statement(symbol: &str, kind: Kind, frequency: Frequency, rows: &[StatementRow]) {
+        let columns: Vec<(String, Vec<Option<f64>>)> = match kind {
+            Kind::Income => income_statements(rows).into_iter()
+                .map(|statement| (statement.period.clone(), statement.values()))
+                .collect(),
+            Kind::Balance => balance_sheets(rows).into_iter()
+                .map(|statement| (statement.period.clone(), statement.values()))
+                .collect(),
+            Kind::CashFlow => cash_flow_statements(rows).into_iter()
+                .map(|statement| (statement.period.clone(), statement.values()))
+                .collect(),
+        };
+
+        if columns.is_empty() {
+            return println!("No {} {} found for {}.", frequency.as_str(), kind.title().to_lowercase(), symbol.to_uppercase())
+        }
+
+        let mut headers = vec!["Item"];
+        headers.extend(columns.iter().rev().map(|(period, _)| period.as_str()));
+
+        let table: Vec<Vec<String>> = kind.items().iter()
+            .enumerate()
+            .map(|(i, (item, label))| {
+                let mut row = vec![label.to_string()];
+                row.extend(columns.iter().rev().map(|(_, values)| match values[i] {
+                    Some(value) if *item == "DilutedEPS" => format!("{:.2}", value),
+                    Some(value) => format_amount(value),
+                    None => "-".to_string()
+                }));
+                row
+            })
+            .collect();
+
+        println!("{} of {} ({})", kind.title(), symbol.to_uppercase(), frequency.as_str());
+        print_table(&headers, &table);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod dcf;
+
+pub mod graham;
\ No newline at end of file
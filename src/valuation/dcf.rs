@@ -0,0 +1,124 @@
+pub mod dcf {
+    use crate::database::queries::queries::{Stock, format_amount, parse_amount};
+    use crate::output::table::table::print_table;
+    use crate::scraper::financial_data::get_financial_data as stock_scraper;
+
+    /// Assumptions of a DCF valuation. Rates are in percent.
+    pub struct Assumptions {
+        pub growth: f64,
+        pub years: u32,
+        pub terminal_growth: f64,
+        pub discount_rate: f64,
+    }
+
+    pub struct Valuation {
+        /// Projected and discounted free cash flow per year.
+        pub cash_flows: Vec<(f64, f64)>,
+        pub terminal_value: f64,
+        pub discounted_terminal_value: f64,
+        pub equity_value: f64,
+        pub value_per_share: f64,
+    }
+
+    /// Discounts the free cash flow of the next years and a terminal value based on 
+    /// the cash flow of the last year growing at the terminal growth rate forever.
+    pub fn calculate(free_cash_flow: f64, shares: f64, assumptions: &Assumptions) -> Result<Valuation, &'static str> {
+        let growth = assumptions.growth / 100.0;
+        let terminal_growth = assumptions.terminal_growth / 100.0;
+        let discount_rate = assumptions.discount_rate / 100.0;
+
+        if discount_rate <= terminal_growth {
+            return Err("The discount rate has to be higher than the terminal growth rate.")
+        }
+
+        if shares <= 0.0 {
+            return Err("The number of shares outstanding has to be positive.")
+        }
+
+        let cash_flows: Vec<(f64, f64)> = (1..=assumptions.years as i32)
+            .map(|year| {
+                let cash_flow = free_cash_flow * (1.0 + growth).powi(year);
+                (cash_flow, cash_flow / (1.0 + discount_rate).powi(year))
+            })
+            .collect();
+
+        let last_cash_flow = cash_flows.last().map(|(cash_flow, _)| *cash_flow).unwrap_or(free_cash_flow);
+        let terminal_value = last_cash_flow * (1.0 + terminal_growth) / (discount_rate - terminal_growth);
+        let discounted_terminal_value = terminal_value / (1.0 + discount_rate).powi(assumptions.years as i32);
+
+        let equity_value = cash_flows.iter().map(|(_, discounted)| discounted).sum::<f64>() + discounted_terminal_value;
+
+        Ok(Valuation {
+            cash_flows,
+            terminal_value,
+            discounted_terminal_value,
+            equity_value,
+            value_per_share: equity_value / shares,
+        })
+    }
+
+    /// Margin of safety in percent, negative if the price is above the intrinsic value.
+    pub fn margin_of_safety(intrinsic_value: f64, price: f64) -> f64 {
+        (intrinsic_value - price) / intrinsic_value * 100.0
+    }
+
+    /// Free cash flow and shares which weren't supplied are taken from the key statistics.
+    pub async fn print_dcf(
+        stock: &Stock, 
+        free_cash_flow: Option<f64>, 
+        shares: Option<f64>, 
+        assumptions: Assumptions
+    ) {
+        let (free_cash_flow, shares) = match (free_cash_flow, shares) {
+            (Some(free_cash_flow), Some(shares)) => (free_cash_flow, shares),
+            (free_cash_flow, shares) => {
+                let url = stock_scraper::Url { symbol: stock.name.clone() };
+                let statistics = match url.scrapped_statistics().await {
+                    Ok(html) => stock_scraper::StockData { symbol: stock.name.clone(), url: html },
+                    Err(e) => return println!("{}", e)
+                };
+
+                (
+                    free_cash_flow.or_else(|| parse_amount(&statistics.levered_free_cash_flow())).unwrap_or(0.0),
+                    shares.or_else(|| parse_amount(&statistics.shares_outstanding())).unwrap_or(0.0)
+                )
+            }
+        };
+
+        if free_cash_flow <= 0.0 {
+            return println!("A DCF valuation needs a positive free cash flow. Pass one with --fcf.")
+        }
+
+        let valuation = match calculate(free_cash_flow, shares, &assumptions) {
+            Ok(valuation) => valuation,
+            Err(e) => return println!("{}", e)
+        };
+
+        println!("DCF valuation of {}", stock.name.to_uppercase());
+        println!("  - Free Cash Flow: {}", format_amount(free_cash_flow));
+        println!("  - Shares Outstanding: {}", format_amount(shares));
+        println!("  - Growth: {:.2}% for {} years, then {:.2}%", assumptions.growth, assumptions.years, assumptions.terminal_growth);
+        println!("  - Discount Rate: {:.2}%", assumptions.discount_rate);
+        println!();
+
+        let rows: Vec<Vec<String>> = valuation.cash_flows.iter()
+            .enumerate()
+            .map(|(i, (cash_flow, discounted))| vec![
+                (i + 1).to_string(), format_amount(*cash_flow), format_amount(*discounted)
+            ])
+            .chain(std::iter::once(vec![
+                "Terminal".to_string(), 
+                format_amount(valuation.terminal_value), 
+                format_amount(valuation.discounted_terminal_value)
+            ]))
+            .collect();
+
+        print_table(&["Year", "Free Cash Flow", "Present Value"], &rows);
+
+        println!();
+        println!("Equity Value: {}", format_amount(valuation.equity_value));
+        println!("Intrinsic Value per Share: {:.2}", valuation.value_per_share);
+        println!("Current Price: {}", stock.current_price);
+        println!("Margin of Safety: {:.2}%", margin_of_safety(valuation.value_per_share, stock.current_price));
+    }
+}
@@ -0,0 +1,91 @@
+pub mod graham {
+    use crate::database::queries::queries::Stock;
+    use crate::output::table::table::print_table;
+    use crate::valuation::dcf::dcf::margin_of_safety;
+
+    /// sqrt(22.5 * EPS * BVPS), the highest price Graham would pay for a defensive stock:
+    /// a P/E of 15 times a price to book of 1.5.
+    pub fn graham_number(eps: f64, bvps: f64) -> Option<f64> {
+        if eps <= 0.0 || bvps <= 0.0 {
+            return None
+        }
+
+        Some((22.5 * eps * bvps).sqrt())
+    }
+
+    /// EPS * (8.5 + 2g) * 4.4 / Y, with the expected growth g in percent per year and 
+    /// the current yield Y of AAA corporate bonds in percent.
+    pub fn graham_growth_value(eps: f64, growth: f64, bond_yield: f64) -> Option<f64> {
+        if eps <= 0.0 || bond_yield <= 0.0 {
+            return None
+        }
+
+        Some(eps * (8.5 + 2.0 * growth) * 4.4 / bond_yield)
+    }
+
+    /// Growth implied by the P/E and PEG ratio, since PEG = P/E / growth.
+    pub fn implied_growth(pe_ratio: f64, peg_ratio: f64) -> Option<f64> {
+        if pe_ratio <= 0.0 || peg_ratio <= 0.0 {
+            return None
+        }
+
+        Some(pe_ratio / peg_ratio)
+    }
+
+    pub fn print_value(stock: &Stock, growth: Option<f64>, bond_yield: f64) {
+        let eps = stock.eps_ttm;
+        let price = stock.current_price;
+
+        let (growth, growth_source) = match growth {
+            Some(growth) => (Some(growth), "input".to_string()),
+            None => (
+                implied_growth(stock.pe_ratio, stock.peg_ratio), 
+                format!("P/E {} / PEG {}", stock.pe_ratio, stock.peg_ratio)
+            )
+        };
+
+        let earnings_yield = if price > 0.0 { eps / price * 100.0 } else { 0.0 };
+        // Price at which the earnings yield would equal the bond yield.
+        let earnings_yield_value = if eps > 0.0 && bond_yield > 0.0 { Some(eps / (bond_yield / 100.0)) } else { None };
+
+        let estimates = [
+            (
+                "Graham Number", 
+                graham_number(eps, stock.bvps), 
+                format!("EPS {}, BVPS {}", eps, stock.bvps)
+            ),
+            (
+                "Graham Growth Formula", 
+                growth.and_then(|growth| graham_growth_value(eps, growth, bond_yield)),
+                match growth {
+                    Some(growth) => format!("EPS {}, growth {:.2}% ({}), bond yield {}%", eps, growth, growth_source, bond_yield),
+                    None => format!("no growth, pass --growth or store a P/E and PEG ratio ({})", growth_source)
+                }
+            ),
+            (
+                "Earnings Yield", 
+                earnings_yield_value,
+                format!("earnings yield {:.2}% vs. bond yield {}%", earnings_yield, bond_yield)
+            ),
+        ];
+
+        let rows: Vec<Vec<String>> = estimates.iter()
+            .map(|(method, value, inputs)| match value {
+                Some(value) => vec![
+                    method.to_string(),
+                    format!("{:.2}", value),
+                    format!("{:.2}%", margin_of_safety(*value, price)),
+                    inputs.clone(),
+                ],
+                None => vec![method.to_string(), "-".to_string(), "-".to_string(), inputs.clone()]
+            })
+            .collect();
+
+        println!("Intrinsic value estimates of {} (current price {})", stock.name.to_uppercase(), price);
+        print_table(&["Method", "Value", "Margin of Safety", "Inputs"], &rows);
+
+        if eps <= 0.0 {
+            println!("The EPS is not positive, so the estimates can't be calculated.");
+        }
+    }
+}